@@ -1,11 +1,11 @@
 import { Program, AnchorProvider, Wallet } from "@coral-xyz/anchor";
 import { Connection, Transaction, PublicKey, Commitment, GetProgramAccountsFilter } from "@solana/web3.js";
 import { Epochs, IDL } from "./utils/idl/epochs";
-import { EPOCH_PROGRAM_ID, NIFTY_PROGRAM_ID, getAuctionEscrowPda, getAuctionPda, getAuthorityPda, getCollectionMintPda, getNftMintPda, getReputationPda, getTimeMachinePda } from "./utils";
+import { EPOCH_PROGRAM_ID, NIFTY_PROGRAM_ID, getAuctionEscrowPda, getAuctionPda, getAuthorityPda, getBadgeAssetPda, getBadgeConfigPda, getBadgePda, getBidCreditPda, getBidDepositPda, getBidHistoryPda, getCollectionMintPda, getConfigPda, getLeaderboardPda, getNftMintPda, getPointsTablePda, getReputationPda, getSealedBidPda, getStatsPda } from "./utils";
 import { ApiError, SolanaQueryType } from "./errors";
 import { TransactionBuilder } from './transactionBuilder';
 import { Asset } from "./utils/deserialize/deserialize";
-import { Auction } from "./utils/types";
+import { Auction, BadgeTier, ConfigArgs, PointsTableArgs } from "./utils/types";
 import { COLLECTION_OFFSET, EPOCH_SIZE, OWNER_OFFSET } from "./utils/constants/deserializers";
 
 interface EpochClientArgs {
@@ -65,6 +65,36 @@ export class EpochClient {
         }
     }
 
+    public async createInitializeConfigTransaction({ admin, args }: { admin: PublicKey, args: ConfigArgs }): Promise<Transaction> {
+        const transaction = await this.txBuilder.initializeConfig({ admin, args });
+        return transaction;
+    }
+
+    public async createUpdateConfigTransaction({ admin, args }: { admin: PublicKey, args: ConfigArgs }): Promise<Transaction> {
+        const transaction = await this.txBuilder.updateConfig({ admin, args });
+        return transaction;
+    }
+
+    public async createInitializePointsTableTransaction({ admin, args }: { admin: PublicKey, args: PointsTableArgs }): Promise<Transaction> {
+        const transaction = await this.txBuilder.initializePointsTable({ admin, args });
+        return transaction;
+    }
+
+    public async createUpdatePointsTableTransaction({ admin, args }: { admin: PublicKey, args: PointsTableArgs }): Promise<Transaction> {
+        const transaction = await this.txBuilder.updatePointsTable({ admin, args });
+        return transaction;
+    }
+
+    public async createInitializeLeaderboardTransaction({ admin }: { admin: PublicKey }): Promise<Transaction> {
+        const transaction = await this.txBuilder.initializeLeaderboard({ admin });
+        return transaction;
+    }
+
+    public async createSyncLeaderboardTransaction({ contributor }: { contributor: PublicKey }): Promise<Transaction> {
+        const transaction = await this.txBuilder.syncLeaderboard({ contributor });
+        return transaction;
+    }
+
     public async createGroupTransaction({ payer }: { payer: PublicKey }): Promise<Transaction> {
         const transaction = await this.txBuilder.createGroup({ payer });
         return transaction;
@@ -76,44 +106,131 @@ export class EpochClient {
         return transaction;
     }
 
-    public async createBidTransaction({ bidAmount, bidder }: {
+    public async createBidTransaction({ bidAmount, bidder, useCredit, maxAmount, allowlistProof, recipient, referrer }: {
         bidAmount: number,
         bidder: PublicKey,
+        useCredit?: boolean,
+        maxAmount?: number,
+        allowlistProof?: number[][],
+        recipient?: PublicKey,
+        referrer?: PublicKey,
     }): Promise<Transaction> {
         const epoch = await this.getCurrentEpoch();
-        const { highBidder } = await this.fetchAuction({ epoch });
-        const transaction = await this.txBuilder.createBid({ bidAmount, epoch, bidder, highBidder });
+        const transaction = await this.txBuilder.createBid({ bidAmount, epoch, bidder, useCredit, maxAmount, allowlistProof, recipient, referrer });
         return transaction;
     }
 
-    public async createClaimInstruction({ winner, epoch }: {
+    public async createCommitBidTransaction({ bidder, commitment, depositLamports, allowlistProof }: {
+        bidder: PublicKey,
+        commitment: number[],
+        depositLamports: number,
+        allowlistProof?: number[][],
+    }): Promise<Transaction> {
+        const epoch = await this.getCurrentEpoch();
+        const transaction = await this.txBuilder.commitBid({ epoch, bidder, commitment, depositLamports, allowlistProof });
+        return transaction;
+    }
+
+    public async createRevealBidTransaction({ epoch, bidder, bidLamports, salt }: {
+        epoch: number,
+        bidder: PublicKey,
+        bidLamports: number,
+        salt: number[],
+    }): Promise<Transaction> {
+        const transaction = await this.txBuilder.revealBid({ epoch, bidder, bidLamports, salt });
+        return transaction;
+    }
+
+    public async createResolveSealedBidTransaction({ epoch, bidder }: {
+        epoch: number,
+        bidder: PublicKey,
+    }): Promise<Transaction> {
+        const transaction = await this.txBuilder.resolveSealedBid({ epoch, bidder });
+        return transaction;
+    }
+
+    public async createBuyNowTransaction({ buyer, maxPrice, allowlistProof, recipient }: {
+        buyer: PublicKey,
+        maxPrice: number,
+        allowlistProof?: number[][],
+        recipient?: PublicKey,
+    }): Promise<Transaction> {
+        const epoch = await this.getCurrentEpoch();
+        const transaction = await this.txBuilder.buyNow({ epoch, buyer, maxPrice, allowlistProof, recipient });
+        return transaction;
+    }
+
+    public async createClaimInstruction({ winner, epoch, recipient }: {
         winner: PublicKey,
-        epoch: number
+        epoch: number,
+        recipient?: PublicKey,
     }): Promise<Transaction> {
         await this.verifyEpochHasPassed(epoch);
-        const transaction = await this.txBuilder.createClaim({ epoch, winner });
+        const transaction = await this.txBuilder.createClaim({ epoch, winner, recipient });
+        return transaction;
+    }
+
+    public async createSettleTransaction({ epoch, settler }: {
+        epoch: number,
+        settler: PublicKey,
+    }): Promise<Transaction> {
+        const transaction = await this.txBuilder.settleAuction({ epoch, settler });
+        return transaction;
+    }
+
+    public async createTransferWinTransaction({ epoch, winner, newWinner, priceLamports }: {
+        epoch: number,
+        winner: PublicKey,
+        newWinner: PublicKey,
+        priceLamports: number,
+    }): Promise<Transaction> {
+        const transaction = await this.txBuilder.transferWin({ epoch, winner, newWinner, priceLamports });
+        return transaction;
+    }
+
+    public async createReleaseBidDepositTransaction({ epoch, bidder }: {
+        epoch: number,
+        bidder: PublicKey,
+    }): Promise<Transaction> {
+        const transaction = await this.txBuilder.releaseBidDeposit({ epoch, bidder });
+        return transaction;
+    }
+
+    public async createWithdrawCreditTransaction({ bidder }: { bidder: PublicKey }): Promise<Transaction> {
+        const transaction = await this.txBuilder.withdrawCredit({ bidder });
         return transaction;
     }
 
-    public async createTimeMachineInitInstruction({ itemsAvailable, startTime }: {
-        itemsAvailable: number,
-        startTime: number
+    public async createCloseAuctionTransaction({ epoch, closer }: {
+        epoch: number,
+        closer: PublicKey,
     }): Promise<Transaction> {
-        const transaction = await this.txBuilder.createTimeMachine({ itemsAvailable, startTime });
+        const transaction = await this.txBuilder.closeAuction({ epoch, closer });
         return transaction;
     }
 
-    public async createTimeMachineAttemptInstruction({ payer }: {
-        payer: PublicKey
+    public async createBadgeGroupTransaction({ payer, tiers }: {
+        payer: PublicKey,
+        tiers: BadgeTier[],
     }): Promise<Transaction> {
-        const transaction = await this.txBuilder.attemptTimeMachine({ payer });
+        const transaction = await this.txBuilder.createBadgeGroup({ payer, tiers });
         return transaction;
     }
 
-    public async createRedeemFromTimeMachineInstruction({ payer }: {
-        payer: PublicKey
+    public async createUpdateBadgeTiersTransaction({ admin, tiers }: {
+        admin: PublicKey,
+        tiers: BadgeTier[],
     }): Promise<Transaction> {
-        const transaction = await this.txBuilder.redeemFromTimeMachine({ payer });
+        const transaction = await this.txBuilder.updateBadgeTiers({ admin, tiers });
+        return transaction;
+    }
+
+    public async createMintBadgeTransaction({ contributor, tier, currentTier }: {
+        contributor: PublicKey,
+        tier: number,
+        currentTier?: number,
+    }): Promise<Transaction> {
+        const transaction = await this.txBuilder.mintBadge({ contributor, tier, currentTier });
         return transaction;
     }
 
@@ -164,9 +281,48 @@ export class EpochClient {
         return { epoch, extensions, assetWithoutExtensions, png };
     }
 
-    public async fetchMinterDetails() {
-        const timeMachine = getTimeMachinePda(this.program);
-        const data = await this.program.account.timeMachine.fetch(timeMachine);
+    public async fetchConfig() {
+        const data = await this.program.account.config.fetch(getConfigPda(this.program));
+        return data;
+    }
+
+    public async fetchStats() {
+        const data = await this.program.account.stats.fetch(getStatsPda(this.program));
+        return data;
+    }
+
+    public async fetchPointsTable() {
+        const data = await this.program.account.pointsTable.fetch(getPointsTablePda(this.program));
+        return data;
+    }
+
+    public async fetchLeaderboard() {
+        const data = await this.program.account.leaderboard.fetch(getLeaderboardPda(this.program));
+        return data;
+    }
+
+    public async fetchBidCredit({ bidder }: { bidder: PublicKey }) {
+        const data = await this.program.account.bidCredit.fetch(getBidCreditPda(bidder, this.program));
+        return data;
+    }
+
+    public async fetchBidDeposit({ epoch, bidder }: { epoch: number, bidder: PublicKey }) {
+        const data = await this.program.account.bidDeposit.fetch(getBidDepositPda(epoch, bidder, this.program));
+        return data;
+    }
+
+    public async fetchSealedBid({ epoch, bidder }: { epoch: number, bidder: PublicKey }) {
+        const data = await this.program.account.sealedBid.fetch(getSealedBidPda(epoch, bidder, this.program));
+        return data;
+    }
+
+    public async fetchBadgeConfig() {
+        const data = await this.program.account.badgeConfig.fetch(getBadgeConfigPda(this.program));
+        return data;
+    }
+
+    public async fetchBadge({ contributor }: { contributor: PublicKey }) {
+        const data = await this.program.account.badge.fetch(getBadgePda(contributor, this.program));
         return data;
     }
 
@@ -186,6 +342,18 @@ export class EpochClient {
         return getNftMintPda(this.program, epoch);
     }
 
+    public fetchAuctionAddress({ epoch }: { epoch: number }) {
+        return getAuctionPda(epoch, this.program);
+    }
+
+    public fetchBidHistoryAddress({ epoch }: { epoch: number }) {
+        return getBidHistoryPda(epoch, this.program);
+    }
+
+    public fetchBadgeAssetAddress({ contributor, tier }: { contributor: PublicKey, tier: number }) {
+        return getBadgeAssetPda(contributor, tier, this.program);
+    }
+
     public async fetchDeserializedGroupAsset() {
         const asset = this.fetchGroupPda();
         const { data } = await this.program.provider.connection.getAccountInfo(asset);
@@ -1,30 +1,61 @@
-import { PublicKey, Transaction, SystemProgram, ComputeBudgetProgram } from "@solana/web3.js";
+import { PublicKey, Transaction, SystemProgram, ComputeBudgetProgram, AccountMeta } from "@solana/web3.js";
 import { Program, BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import {
-    AUTHORITY,
-    CREATOR1_WALLET,
-    CREATOR2_WALLET,
-    DAO_TREASURY,
     NIFTY_PROGRAM_ID,
     SYSTEM_PROGRAM,
     getAuctionEscrowPda,
     getAuctionPda,
+    getAuctionTokenEscrowPda,
     getAuthorityPda,
+    getBadgeAssetPda,
+    getBadgeConfigPda,
+    getBadgeGroupPda,
+    getBadgePda,
+    getBidCreditPda,
+    getBidDepositPda,
+    getBidHistoryPda,
     getCollectionMintPda,
+    getConfigPda,
+    getEscrowLedgerPda,
+    getEventAuthorityPda,
+    getLeaderboardPda,
     getNftMintPda,
+    getPointsTablePda,
     getReputationPda,
-    getTimeMachinePda,
-    getTimeMachineReceiptPda
+    getSealedBidPda,
+    getStatsPda,
+    getTokenCreditPda,
+    getTokenCreditVaultPda,
 } from "../utils";
 import { Epochs } from "../utils";
 import { ApiError, SolanaQueryType, SolanaTxType } from "../errors";
 import { COMPUTE_BUDGET } from "../utils/constants/computeBudget";
+import { BadgeTier, ConfigArgs, PointsTableArgs } from "../utils/types";
 
 interface AuctionBidParams {
     bidAmount: number;
     epoch: number;
     bidder: PublicKey;
-    highBidder: PublicKey;
+    useCredit?: boolean;
+    maxAmount?: number;
+    allowlistProof?: number[][];
+    recipient?: PublicKey;
+    referrer?: PublicKey;
+}
+
+interface ConfigParams {
+    admin: PublicKey;
+    args: ConfigArgs;
+}
+
+interface PointsTableParams {
+    admin: PublicKey;
+    args: PointsTableArgs;
+}
+
+interface AdminParams {
+    admin: PublicKey;
 }
 
 interface CreateGroupParams {
@@ -39,19 +70,76 @@ interface InitEpochAssetParams {
 interface ClaimParams {
     epoch: number;
     winner: PublicKey;
+    recipient?: PublicKey;
 }
 
-interface CreateMinterParams {
-    itemsAvailable: number;
-    startTime: number;
+interface SettleParams {
+    epoch: number;
+    settler: PublicKey;
 }
 
-interface ClaimFromMinterParams {
-    payer: PublicKey;
+interface CommitBidParams {
+    epoch: number;
+    bidder: PublicKey;
+    commitment: number[];
+    depositLamports: number;
+    allowlistProof?: number[][];
 }
 
-interface RedeemFromMinterParams {
+interface RevealBidParams {
+    epoch: number;
+    bidder: PublicKey;
+    bidLamports: number;
+    salt: number[];
+}
+
+interface BidderParams {
+    epoch: number;
+    bidder: PublicKey;
+}
+
+interface BuyNowParams {
+    epoch: number;
+    buyer: PublicKey;
+    maxPrice: number;
+    allowlistProof?: number[][];
+    recipient?: PublicKey;
+}
+
+interface TransferWinParams {
+    epoch: number;
+    winner: PublicKey;
+    newWinner: PublicKey;
+    priceLamports: number;
+}
+
+interface CloseAuctionParams {
+    epoch: number;
+    closer: PublicKey;
+}
+
+interface WithdrawCreditParams {
+    bidder: PublicKey;
+}
+
+interface CreateBadgeGroupParams {
     payer: PublicKey;
+    tiers: BadgeTier[];
+}
+
+interface UpdateBadgeTiersParams {
+    admin: PublicKey;
+    tiers: BadgeTier[];
+}
+
+interface BadgeParams {
+    contributor: PublicKey;
+    tier: number;
+    currentTier?: number;
+}
+
+interface SyncLeaderboardParams {
+    contributor: PublicKey;
 }
 
 
@@ -62,34 +150,180 @@ export class TransactionBuilder {
         this.program = program;
     }
 
+    public async initializeConfig({
+        admin,
+        args,
+    }: ConfigParams): Promise<Transaction> {
+        const accounts = {
+            payer: admin,
+            config: getConfigPda(this.program),
+            escrowLedger: getEscrowLedgerPda(this.program),
+            stats: getStatsPda(this.program),
+            systemProgram: SYSTEM_PROGRAM,
+        };
+
+        try {
+            const instruction = await this.program.methods.initializeConfig(args)
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async updateConfig({
+        admin,
+        args,
+    }: ConfigParams): Promise<Transaction> {
+        const accounts = {
+            admin,
+            config: getConfigPda(this.program),
+        };
+
+        try {
+            const instruction = await this.program.methods.updateConfig(args)
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async initializePointsTable({
+        admin,
+        args,
+    }: PointsTableParams): Promise<Transaction> {
+        const accounts = {
+            admin,
+            config: getConfigPda(this.program),
+            pointsTable: getPointsTablePda(this.program),
+            systemProgram: SYSTEM_PROGRAM,
+        };
+
+        try {
+            const instruction = await this.program.methods.initializePointsTable(args)
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async updatePointsTable({
+        admin,
+        args,
+    }: PointsTableParams): Promise<Transaction> {
+        const accounts = {
+            admin,
+            config: getConfigPda(this.program),
+            pointsTable: getPointsTablePda(this.program),
+        };
+
+        try {
+            const instruction = await this.program.methods.updatePointsTable(args)
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async initializeLeaderboard({
+        admin,
+    }: AdminParams): Promise<Transaction> {
+        const accounts = {
+            admin,
+            config: getConfigPda(this.program),
+            leaderboard: getLeaderboardPda(this.program),
+            systemProgram: SYSTEM_PROGRAM,
+        };
+
+        try {
+            const instruction = await this.program.methods.initializeLeaderboard()
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async syncLeaderboard({
+        contributor,
+    }: SyncLeaderboardParams): Promise<Transaction> {
+        const accounts = {
+            reputation: getReputationPda(contributor, this.program),
+            config: getConfigPda(this.program),
+            leaderboard: getLeaderboardPda(this.program),
+        };
+
+        try {
+            const instruction = await this.program.methods.syncLeaderboard()
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
     public async createBid({
         epoch,
         bidAmount,
         bidder,
-        highBidder,
+        useCredit = false,
+        maxAmount,
+        allowlistProof = [],
+        recipient,
+        referrer,
     }: AuctionBidParams): Promise<Transaction> {
         const auctionPda = getAuctionPda(epoch, this.program);
-        const reputation = getReputationPda(bidder, this.program);
-        const auctionEscrow = getAuctionEscrowPda(this.program);
+        const { params } = await this.program.account.auction.fetch(auctionPda);
+        const bidMint = params.bidMint;
+
         const accounts = {
             bidder,
-            highBidder,
-            auctionEscrow,
             auction: auctionPda,
-            reputation,
+            auctionEscrow: getAuctionEscrowPda(this.program),
+            escrowLedger: getEscrowLedgerPda(this.program),
+            stats: getStatsPda(this.program),
+            bidCredit: getBidCreditPda(bidder, this.program),
+            bidDeposit: getBidDepositPda(epoch, bidder, this.program),
+            bidHistory: getBidHistoryPda(epoch, this.program),
+            reputation: getReputationPda(bidder, this.program),
+            config: getConfigPda(this.program),
+            pointsTable: getPointsTablePda(this.program),
+            leaderboard: getLeaderboardPda(this.program),
+            referrer: referrer ?? null,
+            referrerCredit: referrer ? getBidCreditPda(referrer, this.program) : null,
             systemProgram: SYSTEM_PROGRAM,
+            bidMint: bidMint ?? null,
+            bidderTokenAccount: bidMint ? getAssociatedTokenAddressSync(bidMint, bidder) : null,
+            auctionTokenEscrow: bidMint ? getAuctionTokenEscrowPda(epoch, this.program) : null,
+            tokenCredit: bidMint ? getTokenCreditPda(bidMint, bidder, this.program) : null,
+            tokenCreditVault: bidMint ? getTokenCreditVaultPda(bidMint, this.program) : null,
+            referrerTokenCredit: bidMint && referrer ? getTokenCreditPda(bidMint, referrer, this.program) : null,
+            authority: bidMint ? getAuthorityPda(this.program) : null,
+            tokenProgram: bidMint ? TOKEN_PROGRAM_ID : null,
+            eventAuthority: getEventAuthorityPda(this.program),
+            program: this.program.programId,
         };
 
         const args = {
             epoch: new BN(epoch),
             bidAmount: new BN(bidAmount),
+            maxAmount: maxAmount === undefined ? null : new BN(maxAmount),
         };
 
         try {
             const computeInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_BUDGET.AUCTION_BID });
 
             const bidInstruction = await this.program.methods
-                .auctionBid(args.epoch, args.bidAmount)
+                .auctionBid(args.epoch, args.bidAmount, useCredit, args.maxAmount, allowlistProof, recipient ?? null)
                 .accountsStrict(accounts)
                 .instruction();
 
@@ -108,6 +342,7 @@ export class TransactionBuilder {
 
         const accounts = {
             payer: payer,
+            config: getConfigPda(this.program),
             asset: groupAsset,
             authority: groupAuthority,
             systemProgram: SystemProgram.programId,
@@ -142,7 +377,14 @@ export class TransactionBuilder {
             systemProgram: SystemProgram.programId,
             ossProgram: NIFTY_PROGRAM_ID,
             auction: auctionPda,
+            bidHistory: getBidHistoryPda(epoch, this.program),
             reputation: reputationPda,
+            config: getConfigPda(this.program),
+            pointsTable: getPointsTablePda(this.program),
+            leaderboard: getLeaderboardPda(this.program),
+            stats: getStatsPda(this.program),
+            eventAuthority: getEventAuthorityPda(this.program),
+            program: this.program.programId,
         };
 
         const computeInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_BUDGET.INITIALIZE_EPOCH });
@@ -166,40 +408,32 @@ export class TransactionBuilder {
     public async createClaim({
         epoch,
         winner,
+        recipient,
     }: ClaimParams): Promise<Transaction> {
         const auctionPda = getAuctionPda(epoch, this.program);
-        const { highBidder } = await this.program.account.auction.fetch(auctionPda);
-        const auctionEscrow = getAuctionEscrowPda(this.program);
-        const reputationPda = getReputationPda(highBidder, this.program);
-        const asset = getNftMintPda(this.program, epoch);
-        const group = getCollectionMintPda(this.program);
-        const groupAuthority = getAuthorityPda(this.program);
+        const auction = await this.program.account.auction.fetch(auctionPda);
+        const { highBidder } = auction;
 
         if (winner.toBase58() !== highBidder.toBase58()) {
             throw ApiError.solanaQueryError(SolanaQueryType.INVALID_WINNER);
         }
 
+        const { settlementAccounts, creatorAccounts } = await this.getSettlementAccounts(epoch, auction);
         const accounts = {
             winner,
+            recipient: recipient ?? this.getAssetRecipient(auction),
             auction: auctionPda,
-            auctionEscrow,
-            reputation: reputationPda,
-            systemProgram: SystemProgram.programId,
-            daoTreasury: DAO_TREASURY,
-            creator1Wallet: CREATOR1_WALLET,
-            creator2Wallet: CREATOR2_WALLET,
-            asset,
-            authority: groupAuthority,
-            ossProgram: NIFTY_PROGRAM_ID,
-            group
+            reputation: getReputationPda(highBidder, this.program),
+            ...settlementAccounts,
         };
 
         try {
 
             const computeInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_BUDGET.CLAIM_AUCTION });
 
-            const claimInstruction = await this.program.methods.auctionClaim(new BN(epoch))
-                .accounts(accounts)
+            const claimInstruction = await this.program.methods.auctionClaim(new BN(epoch), recipient ?? null)
+                .accountsStrict(accounts)
+                .remainingAccounts(creatorAccounts)
                 .instruction();
             const transaction = new Transaction().add(computeInstruction).add(claimInstruction);
             return transaction;
@@ -208,81 +442,418 @@ export class TransactionBuilder {
         }
     }
 
-    public async createTimeMachine({
-        itemsAvailable,
-        startTime,
-    }: CreateMinterParams): Promise<Transaction> {
+    public async settleAuction({
+        epoch,
+        settler,
+    }: SettleParams): Promise<Transaction> {
+        const auctionPda = getAuctionPda(epoch, this.program);
+        const auction = await this.program.account.auction.fetch(auctionPda);
+
+        const { settlementAccounts, creatorAccounts } = await this.getSettlementAccounts(epoch, auction);
         const accounts = {
-            authority: AUTHORITY,
-            timeMachine: getTimeMachinePda(this.program),
-            systemProgram: SystemProgram.programId,
+            settler,
+            auction: auctionPda,
+            winner: auction.highBidder,
+            recipient: this.getAssetRecipient(auction),
+            winnerReputation: getReputationPda(auction.highBidder, this.program),
+            settlerReputation: getReputationPda(settler, this.program),
+            ...settlementAccounts,
         };
 
         try {
+            const computeInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_BUDGET.CLAIM_AUCTION });
 
-            const instruction = await this.program.methods.timeMachineInit(new BN(itemsAvailable), new BN(startTime))
-                .accounts(accounts)
+            const settleInstruction = await this.program.methods.settleAuction(new BN(epoch))
+                .accountsStrict(accounts)
+                .remainingAccounts(creatorAccounts)
                 .instruction();
-
-            const transaction = new Transaction().add(instruction);
-            return transaction;
+            return new Transaction().add(computeInstruction).add(settleInstruction);
         } catch (error) {
             throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
         }
     }
 
-    public async attemptTimeMachine({
-        payer
-    }: ClaimFromMinterParams): Promise<Transaction> {
+    public async commitBid({
+        epoch,
+        bidder,
+        commitment,
+        depositLamports,
+        allowlistProof = [],
+    }: CommitBidParams): Promise<Transaction> {
         const accounts = {
-            payer,
-            timeMachine: getTimeMachinePda(this.program),
-            receipt: getTimeMachineReceiptPda(this.program, payer),
-            creator1Wallet: CREATOR1_WALLET,
-            creator2Wallet: CREATOR2_WALLET,
-            systemProgram: SystemProgram.programId,
+            bidder,
+            auction: getAuctionPda(epoch, this.program),
+            sealedBid: getSealedBidPda(epoch, bidder, this.program),
+            auctionEscrow: getAuctionEscrowPda(this.program),
+            escrowLedger: getEscrowLedgerPda(this.program),
+            stats: getStatsPda(this.program),
+            bidCredit: getBidCreditPda(bidder, this.program),
+            reputation: getReputationPda(bidder, this.program),
+            config: getConfigPda(this.program),
+            pointsTable: getPointsTablePda(this.program),
+            leaderboard: getLeaderboardPda(this.program),
+            systemProgram: SYSTEM_PROGRAM,
+            eventAuthority: getEventAuthorityPda(this.program),
+            program: this.program.programId,
         };
 
         try {
-            const instruction = await this.program.methods.timeMachineAttempt()
-                .accounts(accounts)
+            const computeInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_BUDGET.AUCTION_BID });
+
+            const instruction = await this.program.methods
+                .commitBid(new BN(epoch), commitment, new BN(depositLamports), allowlistProof)
+                .accountsStrict(accounts)
                 .instruction();
+            return new Transaction().add(computeInstruction).add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
 
-            const transaction = new Transaction().add(instruction);
-            return transaction;
+    public async revealBid({
+        epoch,
+        bidder,
+        bidLamports,
+        salt,
+    }: RevealBidParams): Promise<Transaction> {
+        const accounts = {
+            bidder,
+            auction: getAuctionPda(epoch, this.program),
+            sealedBid: getSealedBidPda(epoch, bidder, this.program),
+        };
+
+        try {
+            const instruction = await this.program.methods
+                .revealBid(new BN(epoch), new BN(bidLamports), salt)
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
         } catch (error) {
             throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
         }
     }
 
-    public async redeemFromTimeMachine({
-        payer
-    }: RedeemFromMinterParams): Promise<Transaction> {
-        const timeMachineReceipt = getTimeMachineReceiptPda(this.program, payer);
-        const claim = await this.program.account.timeMachineReceipt.fetch(timeMachineReceipt);
+    public async resolveSealedBid({
+        epoch,
+        bidder,
+    }: BidderParams): Promise<Transaction> {
+        const { daoTreasury } = await this.program.account.config.fetch(getConfigPda(this.program));
+        const accounts = {
+            bidder,
+            auction: getAuctionPda(epoch, this.program),
+            sealedBid: getSealedBidPda(epoch, bidder, this.program),
+            bidCredit: getBidCreditPda(bidder, this.program),
+            auctionEscrow: getAuctionEscrowPda(this.program),
+            escrowLedger: getEscrowLedgerPda(this.program),
+            stats: getStatsPda(this.program),
+            config: getConfigPda(this.program),
+            daoTreasury,
+            systemProgram: SYSTEM_PROGRAM,
+        };
 
+        try {
+            const instruction = await this.program.methods
+                .resolveSealedBid(new BN(epoch))
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async buyNow({
+        epoch,
+        buyer,
+        maxPrice,
+        allowlistProof = [],
+        recipient,
+    }: BuyNowParams): Promise<Transaction> {
+        const { daoTreasury, creators } = await this.program.account.config.fetch(getConfigPda(this.program));
         const accounts = {
-            asset: getNftMintPda(this.program, claim.epoch.toNumber()),
-            payer: payer,
+            buyer,
+            recipient: recipient ?? buyer,
+            auction: getAuctionPda(epoch, this.program),
+            auctionEscrow: getAuctionEscrowPda(this.program),
+            stats: getStatsPda(this.program),
+            bidCredit: getBidCreditPda(buyer, this.program),
+            reputation: getReputationPda(buyer, this.program),
+            systemProgram: SYSTEM_PROGRAM,
+            config: getConfigPda(this.program),
+            pointsTable: getPointsTablePda(this.program),
+            leaderboard: getLeaderboardPda(this.program),
+            daoTreasury,
+            asset: getNftMintPda(this.program, epoch),
+            authority: getAuthorityPda(this.program),
+            ossProgram: NIFTY_PROGRAM_ID,
             group: getCollectionMintPda(this.program),
+            eventAuthority: getEventAuthorityPda(this.program),
+            program: this.program.programId,
+        };
+        const creatorAccounts: AccountMeta[] = creators.map(({ address }) => ({
+            pubkey: address,
+            isSigner: false,
+            isWritable: true,
+        }));
+
+        try {
+            const computeInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_BUDGET.CLAIM_AUCTION });
+
+            const instruction = await this.program.methods
+                .buyNow(new BN(epoch), new BN(maxPrice), allowlistProof, recipient ?? null)
+                .accountsStrict(accounts)
+                .remainingAccounts(creatorAccounts)
+                .instruction();
+            return new Transaction().add(computeInstruction).add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async transferWin({
+        epoch,
+        winner,
+        newWinner,
+        priceLamports,
+    }: TransferWinParams): Promise<Transaction> {
+        const accounts = {
+            winner,
+            newWinner,
+            auction: getAuctionPda(epoch, this.program),
+            auctionEscrow: getAuctionEscrowPda(this.program),
+            escrowLedger: getEscrowLedgerPda(this.program),
+            stats: getStatsPda(this.program),
+            winnerCredit: getBidCreditPda(winner, this.program),
+            winnerDeposit: getBidDepositPda(epoch, winner, this.program),
+            newWinnerDeposit: getBidDepositPda(epoch, newWinner, this.program),
+            newWinnerCredit: getBidCreditPda(newWinner, this.program),
+            newWinnerReputation: getReputationPda(newWinner, this.program),
+            systemProgram: SYSTEM_PROGRAM,
+            eventAuthority: getEventAuthorityPda(this.program),
+            program: this.program.programId,
+        };
+
+        try {
+            const instruction = await this.program.methods
+                .transferWin(new BN(epoch), new BN(priceLamports))
+                .accountsStrict(accounts)
+                .instruction();
+            // The new winner only has to sign when paying the seller
+            if (priceLamports > 0) {
+                instruction.keys.find(({ pubkey }) => pubkey.equals(newWinner)).isSigner = true;
+            }
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async releaseBidDeposit({
+        epoch,
+        bidder,
+    }: BidderParams): Promise<Transaction> {
+        const auctionPda = getAuctionPda(epoch, this.program);
+        const { params: { bidMint } } = await this.program.account.auction.fetch(auctionPda);
+        const accounts = {
+            auction: auctionPda,
+            bidder,
+            bidDeposit: getBidDepositPda(epoch, bidder, this.program),
+            bidCredit: getBidCreditPda(bidder, this.program),
+            auctionTokenEscrow: bidMint ? getAuctionTokenEscrowPda(epoch, this.program) : null,
+            tokenCredit: bidMint ? getTokenCreditPda(bidMint, bidder, this.program) : null,
+            tokenCreditVault: bidMint ? getTokenCreditVaultPda(bidMint, this.program) : null,
+            tokenProgram: bidMint ? TOKEN_PROGRAM_ID : null,
+        };
+
+        try {
+            const instruction = await this.program.methods
+                .releaseBidDeposit(new BN(epoch))
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async withdrawCredit({
+        bidder,
+    }: WithdrawCreditParams): Promise<Transaction> {
+        const accounts = {
+            bidder,
+            bidCredit: getBidCreditPda(bidder, this.program),
+            auctionEscrow: getAuctionEscrowPda(this.program),
+            escrowLedger: getEscrowLedgerPda(this.program),
+            systemProgram: SYSTEM_PROGRAM,
+        };
+
+        try {
+            const instruction = await this.program.methods.withdrawCredit()
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async closeAuction({
+        epoch,
+        closer,
+    }: CloseAuctionParams): Promise<Transaction> {
+        const auctionPda = getAuctionPda(epoch, this.program);
+        const { params: { bidMint } } = await this.program.account.auction.fetch(auctionPda);
+        const { daoTreasury } = await this.program.account.config.fetch(getConfigPda(this.program));
+        const accounts = {
+            closer,
+            auction: auctionPda,
+            bidHistory: getBidHistoryPda(epoch, this.program),
             authority: getAuthorityPda(this.program),
-            receipt: timeMachineReceipt,
-            systemProgram: SystemProgram.programId,
-            ossProgram: NIFTY_PROGRAM_ID
+            config: getConfigPda(this.program),
+            auctionTokenEscrow: bidMint ? getAuctionTokenEscrowPda(epoch, this.program) : null,
+            daoTreasuryTokenAccount: bidMint ? getAssociatedTokenAddressSync(bidMint, daoTreasury) : null,
+            tokenProgram: bidMint ? TOKEN_PROGRAM_ID : null,
+            eventAuthority: getEventAuthorityPda(this.program),
+            program: this.program.programId,
         };
 
         try {
-            const instruction = await this.program.methods.timeMachineClaim()
-                .accounts(accounts)
+            const instruction = await this.program.methods
+                .closeAuction(new BN(epoch))
+                .accountsStrict(accounts)
                 .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
 
-            const transaction = new Transaction()
-                .add(instruction);
+    public async createBadgeGroup({
+        payer,
+        tiers,
+    }: CreateBadgeGroupParams): Promise<Transaction> {
+        const accounts = {
+            payer,
+            config: getConfigPda(this.program),
+            badgeConfig: getBadgeConfigPda(this.program),
+            asset: getBadgeGroupPda(this.program),
+            authority: getAuthorityPda(this.program),
+            systemProgram: SYSTEM_PROGRAM,
+            ossProgram: NIFTY_PROGRAM_ID,
+        };
 
-            return transaction;
+        try {
+            const computeInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_BUDGET.CREATE_COLLECTION });
+
+            const instruction = await this.program.methods.createBadgeGroup(tiers)
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(computeInstruction).add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async updateBadgeTiers({
+        admin,
+        tiers,
+    }: UpdateBadgeTiersParams): Promise<Transaction> {
+        const accounts = {
+            admin,
+            config: getConfigPda(this.program),
+            badgeConfig: getBadgeConfigPda(this.program),
+        };
+
+        try {
+            const instruction = await this.program.methods.updateBadgeTiers(tiers)
+                .accountsStrict(accounts)
+                .instruction();
+            return new Transaction().add(instruction);
+        } catch (error) {
+            throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
+        }
+    }
+
+    public async mintBadge({
+        contributor,
+        tier,
+        currentTier,
+    }: BadgeParams): Promise<Transaction> {
+        const accounts = {
+            contributor,
+            reputation: getReputationPda(contributor, this.program),
+            config: getConfigPda(this.program),
+            badgeConfig: getBadgeConfigPda(this.program),
+            badge: getBadgePda(contributor, this.program),
+            badgeAsset: getBadgeAssetPda(contributor, tier, this.program),
+            badgeGroup: getBadgeGroupPda(this.program),
+            authority: getAuthorityPda(this.program),
+            systemProgram: SYSTEM_PROGRAM,
+            ossProgram: NIFTY_PROGRAM_ID,
+            eventAuthority: getEventAuthorityPda(this.program),
+            program: this.program.programId,
+        };
+
+        try {
+            const computeInstruction = ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_BUDGET.MINT_BADGE });
+
+            const instruction = currentTier === undefined
+                ? await this.program.methods.mintBadge(tier)
+                    .accountsStrict(accounts)
+                    .instruction()
+                : await this.program.methods.upgradeBadge(tier)
+                    .accountsStrict({
+                        ...accounts,
+                        currentBadgeAsset: getBadgeAssetPda(contributor, currentTier, this.program),
+                    })
+                    .instruction();
+            return new Transaction().add(computeInstruction).add(instruction);
         } catch (error) {
             throw ApiError.solanaTxError(SolanaTxType.FAILED_TO_GENERATE_IX);
         }
     }
 
+    private getAssetRecipient({ highBidder, recipient }: { highBidder: PublicKey, recipient: PublicKey }): PublicKey {
+        return recipient.equals(PublicKey.default) ? highBidder : recipient;
+    }
+
+    // Accounts shared by claim and settle, plus the creator wallets (token accounts for token auctions) in config order
+    private async getSettlementAccounts(
+        epoch: number,
+        { referrer, params: { bidMint } }: { referrer: PublicKey, params: { bidMint: PublicKey | null } }
+    ) {
+        const { daoTreasury, creators } = await this.program.account.config.fetch(getConfigPda(this.program));
+        const hasReferrer = !referrer.equals(PublicKey.default);
+
+        const settlementAccounts = {
+            auctionEscrow: getAuctionEscrowPda(this.program),
+            escrowLedger: getEscrowLedgerPda(this.program),
+            stats: getStatsPda(this.program),
+            systemProgram: SYSTEM_PROGRAM,
+            config: getConfigPda(this.program),
+            pointsTable: getPointsTablePda(this.program),
+            leaderboard: getLeaderboardPda(this.program),
+            daoTreasury,
+            referrerCredit: hasReferrer && !bidMint ? getBidCreditPda(referrer, this.program) : null,
+            asset: getNftMintPda(this.program, epoch),
+            authority: getAuthorityPda(this.program),
+            ossProgram: NIFTY_PROGRAM_ID,
+            group: getCollectionMintPda(this.program),
+            auctionTokenEscrow: bidMint ? getAuctionTokenEscrowPda(epoch, this.program) : null,
+            daoTreasuryTokenAccount: bidMint ? getAssociatedTokenAddressSync(bidMint, daoTreasury) : null,
+            referrerTokenCredit: hasReferrer && bidMint ? getTokenCreditPda(bidMint, referrer, this.program) : null,
+            tokenCreditVault: hasReferrer && bidMint ? getTokenCreditVaultPda(bidMint, this.program) : null,
+            tokenProgram: bidMint ? TOKEN_PROGRAM_ID : null,
+            eventAuthority: getEventAuthorityPda(this.program),
+            program: this.program.programId,
+        };
+        const creatorAccounts: AccountMeta[] = creators.map(({ address }) => ({
+            pubkey: bidMint ? getAssociatedTokenAddressSync(bidMint, address) : address,
+            isSigner: false,
+            isWritable: true,
+        }));
+
+        return { settlementAccounts, creatorAccounts };
+    }
+
 }
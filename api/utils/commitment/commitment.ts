@@ -0,0 +1,62 @@
+import { PublicKey } from "@solana/web3.js";
+import { keccak_256 } from "@noble/hashes/sha3";
+
+function u64Buffer(value: bigint): Uint8Array {
+    const bytes = new Uint8Array(8);
+    for (let i = 0; i < 8; i++) {
+        bytes[i] = Number(value & BigInt(0xff));
+        value = value >> BigInt(8);
+    }
+    return bytes;
+}
+
+// keccak256(bid_lamports (u64 LE) || salt || bidder), as checked by `reveal_bid`
+function getSealedBidCommitment(bidLamports: number, salt: number[], bidder: PublicKey): number[] {
+    const preimage = Buffer.concat([
+        u64Buffer(BigInt(bidLamports)),
+        Uint8Array.from(salt),
+        bidder.toBuffer()
+    ]);
+    return Array.from(keccak_256(preimage));
+}
+
+// Allowlist leaves are keccak256(wallet)
+function getAllowlistLeaf(wallet: PublicKey): Uint8Array {
+    return keccak_256(wallet.toBuffer());
+}
+
+// Parent nodes hash the sorted pair, so proofs do not need to record sides
+function hashPair(a: Uint8Array, b: Uint8Array): Uint8Array {
+    const [left, right] = Buffer.compare(Buffer.from(a), Buffer.from(b)) <= 0 ? [a, b] : [b, a];
+    return keccak_256(Buffer.concat([left, right]));
+}
+
+// Returns the `gate_allowlist_root` and a proof per wallet, in the order given
+function getAllowlistTree(wallets: PublicKey[]): { root: number[], proofs: number[][][] } {
+    let layer = wallets.map(getAllowlistLeaf);
+    const positions = wallets.map((_, i) => i);
+    const proofs: number[][][] = wallets.map(() => []);
+
+    while (layer.length > 1) {
+        positions.forEach((position, i) => {
+            const sibling = position ^ 1;
+            if (sibling < layer.length) {
+                proofs[i].push(Array.from(layer[sibling]));
+            }
+            positions[i] = position >> 1;
+        });
+        const next: Uint8Array[] = [];
+        for (let i = 0; i < layer.length; i += 2) {
+            next.push(i + 1 < layer.length ? hashPair(layer[i], layer[i + 1]) : layer[i]);
+        }
+        layer = next;
+    }
+
+    return { root: Array.from(layer[0]), proofs };
+}
+
+export {
+    getSealedBidCommitment,
+    getAllowlistLeaf,
+    getAllowlistTree
+};
@@ -0,0 +1 @@
+export * from "./commitment";
\ No newline at end of file
@@ -1,6 +1,7 @@
 export const COMPUTE_BUDGET = {
     CREATE_COLLECTION: 150_000,
     INITIALIZE_EPOCH:200_000,
-    CLAIM_AUCTION: 80_000,
-    AUCTION_BID: 30_000,
+    CLAIM_AUCTION: 200_000,
+    AUCTION_BID: 100_000,
+    MINT_BADGE: 150_000,
 }
\ No newline at end of file
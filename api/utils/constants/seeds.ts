@@ -1,10 +1,26 @@
 export const SEEDS = {
     AUCTION: "Auction",
     AUCTION_ESCROW: "AuctionEscrow",
+    AUCTION_TOKEN_ESCROW: "AuctionTokenEscrow",
     AUTHORITY: "Authority",
+    BADGE: "Badge",
+    BADGE_ASSET: "BadgeAsset",
+    BADGE_CONFIG: "BadgeConfig",
+    BADGE_GROUP: "BadgeGroup",
+    BID_CREDIT: "BidCredit",
+    BID_DEPOSIT: "BidDeposit",
+    BID_HISTORY: "BidHistory",
     COLLECTION: "Collection",
-    TIME_MACHINE: "TimeMachine",
-    TIME_MACHINE_RECEIPT: "TimeMachineReceipt",
+    CONFIG: "Config",
+    ESCROW_LEDGER: "EscrowLedger",
+    EVENT_AUTHORITY: "__event_authority",
+    LEADERBOARD: "Leaderboard",
     NFT_MINT: "NftMint",
-    REPUTATION: "Reputation"
+    POINTS_TABLE: "PointsTable",
+    REPUTATION: "Reputation",
+    SEALED_BID: "SealedBid",
+    STATS: "Stats",
+    TOKEN_CREDIT: "TokenCredit",
+    TOKEN_CREDIT_VAULT: "TokenCreditVault",
+    VAULT: "Vault"
 }
\ No newline at end of file
@@ -1,67 +1,87 @@
 export type Epochs = {
   "version": "0.1.0",
   "name": "epochs",
-  "constants": [
-    {
-      "name": "GREEN_SCREEN",
-      "type": {
-        "defined": "Pixel"
-      },
-      "value": "(255 , 000 , 246)"
-    }
-  ],
   "instructions": [
     {
-      "name": "createGroup",
+      "name": "initializeConfig",
       "accounts": [
         {
           "name": "payer",
           "isMut": true,
-          "isSigner": true
+          "isSigner": true,
+          "docs": [
+            "Only the bootstrap authority can create the config.",
+            "Admin rights are handed to `args.admin` from then on."
+          ]
         },
         {
-          "name": "asset",
+          "name": "config",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "escrowLedger",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "stats",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ossProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ConfigArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "createEpoch",
+      "name": "updateConfig",
       "accounts": [
         {
-          "name": "asset",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "payer",
+          "name": "args",
+          "type": {
+            "defined": "ConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializePointsTable",
+      "accounts": [
+        {
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "group",
-          "isMut": true,
+          "name": "config",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "pointsTable",
           "isMut": true,
           "isSigner": false
         },
@@ -69,117 +89,116 @@ export type Epochs = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "ossProgram",
+          "name": "args",
+          "type": {
+            "defined": "PointsTableArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePointsTable",
+      "accounts": [
+        {
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "auction",
-          "isMut": true,
+          "name": "config",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reputation",
+          "name": "pointsTable",
           "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "inputEpoch",
-          "type": "u64"
+          "name": "args",
+          "type": {
+            "defined": "PointsTableArgs"
+          }
         }
       ]
     },
     {
-      "name": "auctionBid",
+      "name": "initializeLeaderboard",
       "accounts": [
         {
-          "name": "bidder",
+          "name": "admin",
           "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Anybody can bid on an auction.",
-            "No constraits--just need to be a signer"
-          ]
+          "isSigner": true
         },
         {
-          "name": "auction",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "We will update the auction PDA based on the bid",
-            "Seeded on user-input epoch (verified in program to be current epoch)",
-            "See state/auction.rs for more details"
-          ]
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "auctionEscrow",
+          "name": "leaderboard",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The auction escrow account will hold the funds for the auction"
-          ]
+          "isSigner": false
         },
         {
-          "name": "highBidder",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The previous high bidder will have their funds returned to them"
-          ]
-        },
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncLeaderboard",
+      "accounts": [
         {
           "name": "reputation",
-          "isMut": true,
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "PDA that will store the reputation for the user",
-            "Seeded on user's pubkey",
-            "Need to use `init_if_needed` bc we are not sure if the user has a reputation account",
-            "See state/reputation.rs for more details"
+            "Reputation decay parameters"
           ]
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "leaderboard",
+          "isMut": true,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "inputEpoch",
-          "type": "u64"
-        },
-        {
-          "name": "bidAmount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "auctionClaim",
+      "name": "createGroup",
       "accounts": [
         {
-          "name": "winner",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "auction",
-          "isMut": true,
+          "name": "config",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionEscrow",
+          "name": "asset",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reputation",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -188,17 +207,28 @@ export type Epochs = {
           "isSigner": false
         },
         {
-          "name": "daoTreasury",
-          "isMut": true,
+          "name": "ossProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createBadgeGroup",
+      "accounts": [
         {
-          "name": "creator1Wallet",
+          "name": "payer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "creator2Wallet",
+          "name": "badgeConfig",
           "isMut": true,
           "isSigner": false
         },
@@ -209,118 +239,186 @@ export type Epochs = {
         },
         {
           "name": "authority",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ossProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "group",
-          "isMut": true,
+          "name": "ossProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "inputEpoch",
-          "type": "u64"
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": "BadgeTier"
+            }
+          }
         }
       ]
     },
     {
-      "name": "timeMachineInit",
+      "name": "updateBadgeTiers",
       "accounts": [
         {
-          "name": "authority",
-          "isMut": true,
+          "name": "admin",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "timeMachine",
-          "isMut": true,
+          "name": "config",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "badgeConfig",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "itemsAvailable",
-          "type": "u64"
-        },
-        {
-          "name": "startTime",
-          "type": "i64"
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": "BadgeTier"
+            }
+          }
         }
       ]
     },
     {
-      "name": "timeMachineAttempt",
+      "name": "mintBadge",
       "accounts": [
         {
-          "name": "payer",
+          "name": "contributor",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "timeMachine",
-          "isMut": true,
+          "name": "reputation",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receipt",
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay parameters"
+          ]
+        },
+        {
+          "name": "badgeConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator1Wallet",
+          "name": "badge",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "One badge per contributor; `upgrade_badge` replaces its asset"
+          ]
+        },
+        {
+          "name": "badgeAsset",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator2Wallet",
+          "name": "badgeGroup",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "tier",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "timeMachineClaim",
+      "name": "upgradeBadge",
       "accounts": [
         {
-          "name": "asset",
+          "name": "contributor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "reputation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay parameters"
+          ]
+        },
+        {
+          "name": "badgeConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "badge",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "group",
+          "name": "currentBadgeAsset",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "badgeAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "badgeGroup",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receipt",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
@@ -333,282 +431,24 @@ export type Epochs = {
           "name": "ossProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "auction",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "epoch",
-            "type": "u64"
-          },
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "state",
-            "type": {
-              "defined": "AuctionState"
-            }
-          },
-          {
-            "name": "highBidder",
-            "type": "publicKey"
-          },
-          {
-            "name": "highBidLamports",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "reputation",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "contributor",
-            "type": "publicKey"
-          },
-          {
-            "name": "reputation",
-            "type": "u64"
-          },
-          {
-            "name": "initialized",
-            "type": "bool"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "timeMachineReceipt",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "claimer",
-            "type": "publicKey"
-          },
-          {
-            "name": "epoch",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "timeMachine",
-      "docs": [
-        "An asset minter for retroactive epochs.",
-        "The machine will enable the generation of a fixed number of items",
-        "to represent previously occuring epochs."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "itemsAvailable",
-            "type": "u64"
-          },
-          {
-            "name": "itemsRedeemed",
-            "type": "u64"
-          },
-          {
-            "name": "startTime",
-            "type": "i64"
-          },
-          {
-            "name": "active",
-            "type": "bool"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "AuctionState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "UnClaimed"
-          },
-          {
-            "name": "Claimed"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "FutureEpochNotAllowed",
-      "msg": "The input epoch is in the future, which is not allowed"
-    },
-    {
-      "code": 6001,
-      "name": "PastEpochNotAllowed",
-      "msg": "The input epoch is in the past, which is not allowed"
-    },
-    {
-      "code": 6002,
-      "name": "EpochMismatch",
-      "msg": "The input epoch does not match the current epoch"
-    },
-    {
-      "code": 6003,
-      "name": "AssetNotInscribed",
-      "msg": "Inscribe the Asset before creating an asset"
-    },
-    {
-      "code": 6004,
-      "name": "BidTooLow",
-      "msg": "Bid does not meet minimum bid threshold"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidPreviousBidder",
-      "msg": "Previous bidder passed does not match the current high bidder"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidWinner",
-      "msg": "Signer did not win the auction"
-    },
-    {
-      "code": 6007,
-      "name": "AuctionAlreadyClaimed",
-      "msg": "Auction has already been claimed"
-    },
-    {
-      "code": 6008,
-      "name": "InvalidTreasury",
-      "msg": "Invalid treasury account"
-    },
-    {
-      "code": 6009,
-      "name": "InvalidCreator",
-      "msg": "Invalid creator account"
-    },
-    {
-      "code": 6010,
-      "name": "InvalidContributor",
-      "msg": "Contributor does not match signer of the transaction"
-    },
-    {
-      "code": 6011,
-      "name": "Overflow",
-      "msg": "Integer overflow"
-    },
-    {
-      "code": 6012,
-      "name": "Underflow",
-      "msg": "Integer underflow"
-    },
-    {
-      "code": 6013,
-      "name": "InvalidOssProgram",
-      "msg": "Invalid OSS Program"
-    },
-    {
-      "code": 6014,
-      "name": "MinterNotActive",
-      "msg": "Minter is not active"
-    },
-    {
-      "code": 6015,
-      "name": "MinterEmpty",
-      "msg": "Minter is empty"
-    },
-    {
-      "code": 6016,
-      "name": "MinterNotStarted",
-      "msg": "Minter has not started"
-    },
-    {
-      "code": 6017,
-      "name": "MinterStartTimeInPast",
-      "msg": "Cannot start minter in the past"
-    },
-    {
-      "code": 6018,
-      "name": "MinterTooManyItems",
-      "msg": "Minter cannot have more items than the current epoch"
-    }
-  ]
-};
-
-export const IDL: Epochs = {
-  "version": "0.1.0",
-  "name": "epochs",
-  "constants": [
-    {
-      "name": "GREEN_SCREEN",
-      "type": {
-        "defined": "Pixel"
-      },
-      "value": "(255 , 000 , 246)"
-    }
-  ],
-  "instructions": [
-    {
-      "name": "createGroup",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "asset",
-          "isMut": true,
-          "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ossProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "tier",
+          "type": "u8"
+        }
+      ]
     },
     {
       "name": "createEpoch",
@@ -648,16 +488,63 @@ export const IDL: Epochs = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "bidHistory",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Fixed-size ring buffer of the auction's most recent bids"
+          ]
+        },
         {
           "name": "reputation",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "inputEpoch",
-          "type": "u64"
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
         }
       ]
     },
@@ -679,7 +566,7 @@ export const IDL: Epochs = {
           "isSigner": false,
           "docs": [
             "We will update the auction PDA based on the bid",
-            "Seeded on user-input epoch (verified in program to be current epoch)",
+            "Seeded on user-input epoch (auction end slot is verified in program)",
             "See state/auction.rs for more details"
           ]
         },
@@ -692,11 +579,47 @@ export const IDL: Epochs = {
           ]
         },
         {
-          "name": "highBidder",
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Tracks what the escrow owes; new lamports from the bidder are added to it"
+          ]
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "PDA that tracks lamports the bidder can withdraw or re-use",
+            "Seeded on user's pubkey",
+            "See state/bid_credit.rs for more details"
+          ]
+        },
+        {
+          "name": "bidDeposit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "PDA that tracks what the bidder has escrowed in this auction",
+            "Seeded on user-input epoch and user's pubkey",
+            "See state/bid_deposit.rs for more details"
+          ]
+        },
+        {
+          "name": "bidHistory",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The previous high bidder will have their funds returned to them"
+            "The high bid after every bid is appended here so clients can show the bid ladder"
           ]
         },
         {
@@ -710,10 +633,124 @@ export const IDL: Epochs = {
             "See state/reputation.rs for more details"
           ]
         },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay and streak parameters"
+          ]
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "PDA that receives the referral fee if this bid wins, which the referrer withdraws like any bid credit",
+            "Seeded on the referrer's pubkey. Required with `referrer`"
+          ]
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "bidMint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Holds the tokens of the auction's live bids. Owned by the auction PDA",
+            "Created by the first bid on the auction"
+          ]
+        },
+        {
+          "name": "tokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "PDA that tracks tokens of the bid mint the bidder can withdraw, credited when their deposit is released",
+            "Seeded on the bid mint and user's pubkey",
+            "See state/token_credit.rs for more details"
+          ]
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Holds the tokens owed to all `TokenCredit`s of the bid mint. Owned by the authority PDA",
+            "Created by the first bid in the mint"
+          ]
+        },
+        {
+          "name": "referrerTokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token equivalent of `referrer_credit`. Required with `referrer`"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -724,14 +761,41 @@ export const IDL: Epochs = {
         {
           "name": "bidAmount",
           "type": "u64"
+        },
+        {
+          "name": "useCredit",
+          "type": "bool"
+        },
+        {
+          "name": "maxAmount",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "allowlistProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "recipient",
+          "type": {
+            "option": "publicKey"
+          }
         }
       ]
     },
     {
-      "name": "auctionClaim",
+      "name": "commitBid",
       "accounts": [
         {
-          "name": "winner",
+          "name": "bidder",
           "isMut": true,
           "isSigner": true
         },
@@ -741,53 +805,81 @@ export const IDL: Epochs = {
           "isSigner": false
         },
         {
-          "name": "auctionEscrow",
+          "name": "sealedBid",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "One commitment per bidder per auction"
+          ]
         },
         {
-          "name": "reputation",
+          "name": "auctionEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "escrowLedger",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "daoTreasury",
+          "name": "stats",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
         },
         {
-          "name": "creator1Wallet",
+          "name": "bidCredit",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Receives the deposit back (less any forfeit) when the sealed bid is resolved"
+          ]
         },
         {
-          "name": "creator2Wallet",
+          "name": "reputation",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "asset",
-          "isMut": true,
-          "isSigner": false
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay and streak parameters"
+          ]
         },
         {
-          "name": "authority",
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
           "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ossProgram",
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "group",
-          "isMut": true,
+          "name": "program",
+          "isMut": false,
           "isSigner": false
         }
       ],
@@ -795,244 +887,6420 @@ export const IDL: Epochs = {
         {
           "name": "inputEpoch",
           "type": "u64"
+        },
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "depositLamports",
+          "type": "u64"
+        },
+        {
+          "name": "allowlistProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
         }
       ]
     },
     {
-      "name": "timeMachineInit",
+      "name": "revealBid",
       "accounts": [
         {
-          "name": "authority",
-          "isMut": true,
+          "name": "bidder",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "timeMachine",
+          "name": "auction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "sealedBid",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "itemsAvailable",
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "bidLamports",
           "type": "u64"
         },
         {
-          "name": "startTime",
-          "type": "i64"
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "timeMachineAttempt",
+      "name": "resolveSealedBid",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "timeMachine",
+          "name": "bidder",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receipt",
+          "name": "auction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator1Wallet",
+          "name": "sealedBid",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator2Wallet",
+          "name": "bidCredit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "timeMachineClaim",
-      "accounts": [
-        {
-          "name": "asset",
+          "name": "auctionEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "escrowLedger",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "group",
+          "name": "stats",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
         },
         {
-          "name": "authority",
+          "name": "config",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receipt",
-          "isMut": false,
+          "name": "daoTreasury",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "ossProgram",
-          "isMut": false,
-          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "buyNow",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Marks the buyer as a bidder, so they are counted in `Stats::unique_bidders` like any other bidder"
+          ]
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "allowlistProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "recipient",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeAuction",
+      "accounts": [
+        {
+          "name": "closer",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Anybody can close a settled auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidHistory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "daoTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the token escrow still holds tokens"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawCredit",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTokenCredit",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
+    },
+    {
+      "name": "releaseBidDeposit",
+      "accounts": [
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidDeposit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Created with the deposit, so it always exists"
+          ]
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Created with the deposit, so it always exists"
+          ]
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "verifyEscrow",
+      "accounts": [
+        {
+          "name": "auctionEscrow",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sweepExcess",
+      "accounts": [
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "auctionClaim",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the winner may name a new recipient when claiming)"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Credited with the referral fee of the winning bid. Required when `auction.referrer` is set",
+            "Created by the referred bid"
+          ]
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "daoTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Validated against `config.dao_treasury` when distributing"
+          ]
+        },
+        {
+          "name": "referrerTokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token equivalent of `referrer_credit`. Required when `auction.referrer` is set"
+          ]
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Receives the referral fee for `referrer_token_credit`. Required when `auction.referrer` is set"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "recipient",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferWin",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "newWinner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Must sign when paying a price"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "winnerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The seller's credit, receives the price"
+          ]
+        },
+        {
+          "name": "winnerDeposit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The seller's deposit, which backs the winning bid"
+          ]
+        },
+        {
+          "name": "newWinnerDeposit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Takes over the high bid from the seller's deposit"
+          ]
+        },
+        {
+          "name": "newWinnerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`claim` and `release_bid_deposit` need the new winner's credit and reputation accounts, so the seller creates them if needed"
+          ]
+        },
+        {
+          "name": "newWinnerReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "priceLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleAuction",
+      "accounts": [
+        {
+          "name": "settler",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Anybody but the winner can settle"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "winner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "winnerReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "settlerReputation",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "PDA that will store the reputation for the settler",
+            "Need to use `init_if_needed` bc we are not sure if the settler has a reputation account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Credited with the referral fee of the winning bid. Required when `auction.referrer` is set",
+            "Created by the referred bid"
+          ]
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "daoTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Validated against `config.dao_treasury` when distributing"
+          ]
+        },
+        {
+          "name": "referrerTokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token equivalent of `referrer_credit`. Required when `auction.referrer` is set"
+          ]
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Receives the referral fee for `referrer_token_credit`. Required when `auction.referrer` is set"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleUnsoldAuction",
+      "accounts": [
+        {
+          "name": "settler",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "settlerReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawVaultAsset",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateReputation",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "contributor",
+          "type": "publicKey"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "Auction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "AuctionState"
+            }
+          },
+          {
+            "name": "highBidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "highBidLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "params",
+            "docs": [
+              "Snapshot of `Config::auction_params` taken when the auction was created"
+            ],
+            "type": {
+              "defined": "AuctionParams"
+            }
+          },
+          {
+            "name": "endSlot",
+            "docs": [
+              "First slot at which bids are no longer accepted.",
+              "Starts at the first slot of the next epoch and can be pushed back by late bids."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "secondBidLamports",
+            "docs": [
+              "Second highest revealed bid (sealed auctions only), used for second-price settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startSlot",
+            "docs": [
+              "Slot the auction was created in, where a Dutch auction's price starts decaying"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proxyMaxLamports",
+            "docs": [
+              "Hidden maximum of the high bidder, fully escrowed (English auctions only).",
+              "The visible `high_bid_lamports` is only raised up to this when somebody bids against it."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Frontend that referred the leading bid (`Pubkey::default()` for none).",
+              "Paid `Config::referral_bps` of the price at settlement."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Wallet the asset is delivered to instead of the winner (`Pubkey::default()` for the winner).",
+              "Set with the leading bid or named by the winner at claim."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "openDeposits",
+            "docs": [
+              "`BidDeposit` accounts not released yet (English auctions only). The auction cannot be closed until this is 0"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unresolvedSealedBids",
+            "docs": [
+              "`SealedBid` commitments not resolved yet (sealed auctions only). The auction cannot be closed until this is 0"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BadgeConfig",
+      "docs": [
+        "Reputation tiers that can be shown as soulbound badges. Admin-controlled.",
+        "Singleton seeded on `BADGE_CONFIG_SEED`, created alongside the badge group."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tiers",
+            "docs": [
+              "Ordered from lowest to highest threshold; a badge's tier is its index here"
+            ],
+            "type": {
+              "vec": {
+                "defined": "BadgeTier"
+              }
+            }
+          },
+          {
+            "name": "mintedTiers",
+            "docs": [
+              "One past the highest tier any badge has been minted for; `tiers` can't shrink below it"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Badge",
+      "docs": [
+        "A contributor's current reputation badge. Seeded on the contributor's pubkey."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contributor",
+            "type": "publicKey"
+          },
+          {
+            "name": "asset",
+            "docs": [
+              "The soulbound Nifty asset, seeded on `BADGE_ASSET_SEED`, the contributor and the tier"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tier",
+            "docs": [
+              "Index into `BadgeConfig::tiers`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidCredit",
+      "docs": [
+        "Lamports owed to a bidder that are still held in the auction escrow (e.g., after being outbid).",
+        "Seeded on the bidder's pubkey. Can be withdrawn or applied toward a new bid."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "type": "u64"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "hasBid",
+            "docs": [
+              "Set on the owner's first bid or won auction, so referrer-only credits are not counted as bidders"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidDeposit",
+      "docs": [
+        "What a bidder has escrowed in one English auction.",
+        "Seeded on the epoch and the bidder's pubkey. Outbidding somebody moves nothing: once their deposit",
+        "no longer backs the leading bid, anybody can `release_bid_deposit` it into their `BidCredit`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports (or base units of the bid mint) held in escrow for the bidder"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidHistory",
+      "docs": [
+        "The most recent bids on an epoch's auction.",
+        "Seeded on `BID_HISTORY_SEED` and the epoch. Zero-copy ring buffer: once full, each new bid",
+        "overwrites the oldest record, so clients can fetch the bid ladder in a single account read",
+        "(the oldest record is at `total_bids % CAPACITY` once the buffer has wrapped)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "totalBids",
+            "docs": [
+              "Bids ever recorded; the next write goes to `total_bids % CAPACITY`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "bids",
+            "type": {
+              "array": [
+                {
+                  "defined": "BidRecord"
+                },
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Config",
+      "docs": [
+        "Global, admin-controlled program settings.",
+        "Singleton seeded on `CONFIG_SEED`. Replaces the wallets and shares that used to be compiled in."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "daoTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "treasuryShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CreatorShare"
+              }
+            }
+          },
+          {
+            "name": "royaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "auctionParams",
+            "docs": [
+              "Applied to auctions created from now on; live auctions keep their own snapshot"
+            ],
+            "type": {
+              "defined": "AuctionParams"
+            }
+          },
+          {
+            "name": "referralBps",
+            "docs": [
+              "Share of the sale price paid to the frontend that referred the winning bid, before the treasury/creator split"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "reputationParams",
+            "type": {
+              "defined": "ReputationParams"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "EscrowLedger",
+      "docs": [
+        "Solvency accounting for the shared auction escrow.",
+        "Singleton seeded on `ESCROW_LEDGER_SEED`. Tracks every lamport the escrow owes",
+        "(live high bids and unwithdrawn bid credit) so anything above that can be swept to the treasury."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalOutstandingLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Leaderboard",
+      "docs": [
+        "The top contributors by reputation, highest first.",
+        "Singleton seeded on `LEADERBOARD_SEED`. Updated whenever an instruction changes a contributor's reputation;",
+        "since decay is applied lazily, entries reflect each contributor's reputation as of their last update",
+        "and can be refreshed with `sync_leaderboard`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "len",
+            "docs": [
+              "Number of filled entries"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "LeaderboardEntry"
+                },
+                50
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PointsTable",
+      "docs": [
+        "Reputation points awarded for each action. Admin-controlled so points can be tuned and promotions run",
+        "without a program upgrade. Singleton seeded on `POINTS_TABLE_SEED`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "initiate",
+            "type": "u64"
+          },
+          {
+            "name": "bid",
+            "type": "u64"
+          },
+          {
+            "name": "win",
+            "type": "u64"
+          },
+          {
+            "name": "settle",
+            "type": "u64"
+          },
+          {
+            "name": "forceSettle",
+            "type": "u64"
+          },
+          {
+            "name": "bidLamportsPerPoint",
+            "docs": [
+              "Extra bid points for every this many lamports bid (0 to disable size-based points)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxBidSizePoints",
+            "docs": [
+              "Cap on the size-based bid points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "milestoneIntervalEpochs",
+            "docs": [
+              "Epochs divisible by this are milestones (0 to disable)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "milestoneMultiplierBps",
+            "docs": [
+              "Multiplier on all points earned on a milestone epoch's auction, in bps (20,000 = double points)"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Reputation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contributor",
+            "type": "publicKey"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lastActiveEpoch",
+            "docs": [
+              "Last epoch with a bid or initiation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "streak",
+            "docs": [
+              "Consecutive epochs with a bid or initiation, ending at `last_active_epoch`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastDecayEpoch",
+            "docs": [
+              "Epoch decay was last applied up to (0 until the account is first touched after decay was introduced)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initiations",
+            "docs": [
+              "Lifetime count and points (before decay) for each kind of action.",
+              "Accounts migrated from an older layout only count actions from the migration on."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initiationPoints",
+            "type": "u64"
+          },
+          {
+            "name": "bids",
+            "type": "u64"
+          },
+          {
+            "name": "bidPoints",
+            "type": "u64"
+          },
+          {
+            "name": "wins",
+            "type": "u64"
+          },
+          {
+            "name": "winPoints",
+            "type": "u64"
+          },
+          {
+            "name": "settles",
+            "type": "u64"
+          },
+          {
+            "name": "settlePoints",
+            "type": "u64"
+          },
+          {
+            "name": "totalBidLamports",
+            "docs": [
+              "Lamports offered across every accepted open bid in lamport auctions (sealed bids are hidden when committed)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBid",
+      "docs": [
+        "A bidder's commitment in a sealed-bid auction.",
+        "Seeded on the auction epoch and the bidder's pubkey. Closed by `resolve_sealed_bid` once the auction is over."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "commitment",
+            "docs": [
+              "keccak256(bid_lamports (u64 LE) || salt || bidder)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "depositLamports",
+            "docs": [
+              "Lamports escrowed with the commitment. The revealed bid can not exceed it."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "revealed",
+            "type": "bool"
+          },
+          {
+            "name": "bidLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Stats",
+      "docs": [
+        "Protocol-wide totals for dashboards.",
+        "Singleton seeded on `STATS_SEED`. Lamport totals only include lamport auctions.",
+        "`treasury_lamports` also includes forfeited sealed-bid deposits and swept escrow surplus."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionsCreated",
+            "type": "u64"
+          },
+          {
+            "name": "auctionsSettled",
+            "docs": [
+              "Auctions sold (claimed, settled or bought); unsold auctions are not counted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRaisedLamports",
+            "type": "u64"
+          },
+          {
+            "name": "treasuryLamports",
+            "type": "u64"
+          },
+          {
+            "name": "creatorLamports",
+            "type": "u64"
+          },
+          {
+            "name": "referralLamports",
+            "type": "u64"
+          },
+          {
+            "name": "highestPriceLamports",
+            "type": "u64"
+          },
+          {
+            "name": "highestPriceEpoch",
+            "type": "u64"
+          },
+          {
+            "name": "uniqueBidders",
+            "docs": [
+              "Wallets that have ever bid or taken over a win (counted on their `BidCredit`'s first bid)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenCredit",
+      "docs": [
+        "Tokens of one bid mint owed to a wallet (e.g., a released bid deposit), held in the mint's token credit vault.",
+        "Seeded on the mint and the owner's pubkey. Withdrawn by the owner with `withdraw_token_credit`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Base units of `mint`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "AuctionParams",
+      "docs": [
+        "Bidding rules for an auction. Configured globally and copied onto each auction at creation."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reservePriceLamports",
+            "docs": [
+              "No bid below this amount is accepted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minIncrementLamports",
+            "docs": [
+              "Every bid must beat the current high bid by at least this many lamports (must be non-zero)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minIncrementBps",
+            "docs": [
+              "Every bid must beat the current high bid by at least this share of it (0 to disable)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "extensionWindowSlots",
+            "docs": [
+              "A bid landing within this many slots of the end moves the end to `bid slot + window` (0 to disable)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "noBidPolicy",
+            "type": {
+              "defined": "NoBidPolicy"
+            }
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": "AuctionKind"
+            }
+          },
+          {
+            "name": "bidMint",
+            "docs": [
+              "SPL token that bids are placed in, or `None` for lamports (English auctions only).",
+              "For token auctions every amount in these params and on the auction is in the token's base units."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "claimGraceEpochs",
+            "docs": [
+              "Epochs after the auction ends (counting from the epoch it ended in) before anybody can force-settle it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "forfeitUnclaimedToDao",
+            "docs": [
+              "Whether a force-settled asset goes to the DAO treasury instead of the winner"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "gateWindowSlots",
+            "docs": [
+              "During the first `gate_window_slots` slots of the auction only gated bidders may bid (0 to disable)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gateMinReputation",
+            "docs": [
+              "Bidders with at least this much reputation pass the gate (0 to disable this rule)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gateAllowlistRoot",
+            "docs": [
+              "Merkle root of allowlisted wallets that pass the gate (all zeros to disable this rule)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BadgeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "docs": [
+              "Shown in the badge name, e.g. \"Epochs Reputation: Gold\""
+            ],
+            "type": "string"
+          },
+          {
+            "name": "minReputation",
+            "docs": [
+              "Current (decayed) reputation needed to mint or upgrade to this tier"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidRecord",
+      "docs": [
+        "The standing high bid right after a bid was placed. When an existing proxy bid immediately beats the new bid,",
+        "this is the proxy holder at their raised price, so the ladder only shows prices that actually led."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "type": "u64"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatorShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigArgs",
+      "docs": [
+        "Arguments for `initialize_config` and `update_config`.",
+        "Every field is overwritten on update, so clients should send the full desired config."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "daoTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "treasuryShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CreatorShare"
+              }
+            }
+          },
+          {
+            "name": "royaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "auctionParams",
+            "type": {
+              "defined": "AuctionParams"
+            }
+          },
+          {
+            "name": "referralBps",
+            "type": "u16"
+          },
+          {
+            "name": "reputationParams",
+            "type": {
+              "defined": "ReputationParams"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LeaderboardEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contributor",
+            "type": "publicKey"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PointsTableArgs",
+      "docs": [
+        "Arguments for `initialize_points_table` and `update_points_table`.",
+        "Every field is overwritten on update, so clients should send the full desired table."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initiate",
+            "type": "u64"
+          },
+          {
+            "name": "bid",
+            "type": "u64"
+          },
+          {
+            "name": "win",
+            "type": "u64"
+          },
+          {
+            "name": "settle",
+            "type": "u64"
+          },
+          {
+            "name": "forceSettle",
+            "type": "u64"
+          },
+          {
+            "name": "bidLamportsPerPoint",
+            "type": "u64"
+          },
+          {
+            "name": "maxBidSizePoints",
+            "type": "u64"
+          },
+          {
+            "name": "milestoneIntervalEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "milestoneMultiplierBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationParams",
+      "docs": [
+        "How reputation decays and how streaks are rewarded. Configured globally on `Config`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "halfLifeEpochs",
+            "docs": [
+              "Reputation halves every this many epochs (0 to disable decay)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "streakBonusBps",
+            "docs": [
+              "Bonus on streak actions for every consecutive active epoch after the first, in bps of the base points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxStreakBonusBps",
+            "docs": [
+              "Cap on the streak bonus, in bps of the base points"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionKind",
+      "docs": [
+        "How bids are placed and the winner is priced"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "English"
+          },
+          {
+            "name": "Sealed",
+            "fields": [
+              {
+                "name": "revealWindowSlots",
+                "docs": [
+                  "Number of slots after `end_slot` during which bids can be revealed"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "forfeitBps",
+                "docs": [
+                  "Share of an unrevealed deposit sent to the DAO treasury"
+                ],
+                "type": "u16"
+              },
+              {
+                "name": "pricing",
+                "type": {
+                  "defined": "SealedBidPricing"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Dutch",
+            "fields": [
+              {
+                "name": "startPrice",
+                "docs": [
+                  "Price in lamports at `start_slot`"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "floorPrice",
+                "docs": [
+                  "The price never decays below this"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "decayPerSlot",
+                "docs": [
+                  "Lamports taken off the price every slot"
+                ],
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBidPricing",
+      "docs": [
+        "What the winner of a sealed auction pays"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FirstPrice"
+          },
+          {
+            "name": "SecondPrice"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "UnClaimed"
+          },
+          {
+            "name": "Claimed"
+          },
+          {
+            "name": "Unsold"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NoBidPolicy",
+      "docs": [
+        "What happens to the asset of an auction that ends without any bids"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Treasury"
+          },
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Vault"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationAction",
+      "docs": [
+        "The action reputation is awarded for, used to keep the per-action breakdown"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Initiate"
+          },
+          {
+            "name": "Bid"
+          },
+          {
+            "name": "Win"
+          },
+          {
+            "name": "Settle"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "EpochCreated",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "asset",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "initiator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BidPlaced",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bidAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "isHighBidder",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "highBidder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "highBid",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousHighBidder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "refundAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SealedBidCommitted",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositLamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuctionSettled",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referralAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "treasuryAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "creatorAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "bidMint",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuctionUnsold",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "noBidPolicy",
+          "type": {
+            "defined": "NoBidPolicy"
+          },
+          "index": false
+        },
+        {
+          "name": "settler",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReputationChanged",
+      "fields": [
+        {
+          "name": "contributor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "points",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "reputation",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WinTransferred",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "to",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "priceLamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuctionClosed",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "state",
+          "type": {
+            "defined": "AuctionState"
+          },
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidMint",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BadgeMinted",
+      "fields": [
+        {
+          "name": "contributor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "asset",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tier",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "previousAsset",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "FutureEpochNotAllowed",
+      "msg": "The input epoch is in the future, which is not allowed"
+    },
+    {
+      "code": 6001,
+      "name": "PastEpochNotAllowed",
+      "msg": "The input epoch is in the past, which is not allowed"
+    },
+    {
+      "code": 6002,
+      "name": "EpochMismatch",
+      "msg": "The input epoch does not match the current epoch"
+    },
+    {
+      "code": 6003,
+      "name": "AssetNotInscribed",
+      "msg": "Inscribe the Asset before creating an asset"
+    },
+    {
+      "code": 6004,
+      "name": "BidBelowReserve",
+      "msg": "Bid is below the auction's reserve price"
+    },
+    {
+      "code": 6005,
+      "name": "BidBelowMinimumIncrement",
+      "msg": "Bid does not beat the high bid by the minimum increment"
+    },
+    {
+      "code": 6006,
+      "name": "BidBelowPercentageIncrement",
+      "msg": "Bid does not beat the high bid by the minimum percentage increment"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidMaxBid",
+      "msg": "Maximum bid must be at least the bid amount"
+    },
+    {
+      "code": 6008,
+      "name": "BidderNotAllowed",
+      "msg": "Only allowlisted or reputable bidders may bid during the gate window"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidPreviousBidder",
+      "msg": "Previous bidder credit passed does not match the current high bidder"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidWinner",
+      "msg": "Signer did not win the auction"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidRecipient",
+      "msg": "Recipient does not match the auction's asset recipient"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidNewWinner",
+      "msg": "New winner must differ from the current winner and sign when paying a price"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidSettler",
+      "msg": "The winner must claim their own auction"
+    },
+    {
+      "code": 6014,
+      "name": "AuctionAlreadyClaimed",
+      "msg": "Auction has already been claimed"
+    },
+    {
+      "code": 6015,
+      "name": "AuctionEnded",
+      "msg": "Auction has ended"
+    },
+    {
+      "code": 6016,
+      "name": "AuctionNotEnded",
+      "msg": "Auction has not ended yet"
+    },
+    {
+      "code": 6017,
+      "name": "NoBids",
+      "msg": "Auction ended without any bids"
+    },
+    {
+      "code": 6018,
+      "name": "AuctionHasBids",
+      "msg": "Auction has bids and must be claimed or settled"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidAuctionKind",
+      "msg": "Instruction is not supported by this auction's kind"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidAuctionParams",
+      "msg": "Invalid auction parameters"
+    },
+    {
+      "code": 6021,
+      "name": "AuctionNotSettled",
+      "msg": "Auction must be claimed, settled or unsold before it can be closed"
+    },
+    {
+      "code": 6022,
+      "name": "OpenBidDeposits",
+      "msg": "Auction has bid deposits that have not been released"
+    },
+    {
+      "code": 6023,
+      "name": "UnresolvedSealedBids",
+      "msg": "Auction has sealed bids that have not been resolved"
+    },
+    {
+      "code": 6024,
+      "name": "GracePeriodNotOver",
+      "msg": "Claim grace period has not passed"
+    },
+    {
+      "code": 6025,
+      "name": "AssetNotInVault",
+      "msg": "Auction asset is not held in the program vault"
+    },
+    {
+      "code": 6026,
+      "name": "RevealNotOpen",
+      "msg": "Reveal window has not opened yet"
+    },
+    {
+      "code": 6027,
+      "name": "RevealClosed",
+      "msg": "Reveal window has closed"
+    },
+    {
+      "code": 6028,
+      "name": "BidAlreadyRevealed",
+      "msg": "Bid has already been revealed"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidReveal",
+      "msg": "Revealed bid does not match the commitment"
+    },
+    {
+      "code": 6030,
+      "name": "BidExceedsDeposit",
+      "msg": "Revealed bid exceeds the deposit"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidBidder",
+      "msg": "Bidder does not match the sealed bid"
+    },
+    {
+      "code": 6032,
+      "name": "PriceAboveMaximum",
+      "msg": "Current price is above the buyer's maximum price"
+    },
+    {
+      "code": 6033,
+      "name": "MissingTokenAccounts",
+      "msg": "Token auctions require the bid mint, token accounts and token program"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidTokenAccount",
+      "msg": "Token account does not match the auction's bid mint or owner"
+    },
+    {
+      "code": 6035,
+      "name": "TokenCreditNotSupported",
+      "msg": "Bid credit cannot be used in token auctions"
+    },
+    {
+      "code": 6036,
+      "name": "TokenAuctionNotSupported",
+      "msg": "Instruction is not supported for token auctions"
+    },
+    {
+      "code": 6037,
+      "name": "InsufficientCredit",
+      "msg": "Bid credit balance is too low"
+    },
+    {
+      "code": 6038,
+      "name": "NoCredit",
+      "msg": "No bid credit to withdraw"
+    },
+    {
+      "code": 6039,
+      "name": "BidStillLeading",
+      "msg": "Bid deposit still backs the leading bid"
+    },
+    {
+      "code": 6040,
+      "name": "EscrowInsolvent",
+      "msg": "Escrow holds fewer lamports than it owes"
+    },
+    {
+      "code": 6041,
+      "name": "NoEscrowSurplus",
+      "msg": "Escrow has no surplus to sweep"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidTreasury",
+      "msg": "Invalid treasury account"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidCreator",
+      "msg": "Invalid creator account"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidReferrer",
+      "msg": "Referrer account is missing or does not match the auction's referrer"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidAdmin",
+      "msg": "Signer is not the config admin"
+    },
+    {
+      "code": 6046,
+      "name": "TooManyCreators",
+      "msg": "Too many creators"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidBasisPoints",
+      "msg": "Basis points are out of range or shares do not sum to 10,000"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidPointsTable",
+      "msg": "Milestone epochs need a non-zero multiplier"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidContributor",
+      "msg": "Contributor does not match signer of the transaction"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidReputationAccount",
+      "msg": "Account is not a reputation account"
+    },
+    {
+      "code": 6051,
+      "name": "Overflow",
+      "msg": "Integer overflow"
+    },
+    {
+      "code": 6052,
+      "name": "Underflow",
+      "msg": "Integer underflow"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidBadgeTiers",
+      "msg": "Badge tiers must be 1-5 named tiers with increasing thresholds"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidBadgeTier",
+      "msg": "Badge tier does not exist or is not above the current badge"
+    },
+    {
+      "code": 6055,
+      "name": "MintedBadgeTierRemoved",
+      "msg": "Badge tiers cannot drop a tier that badges have been minted for"
+    },
+    {
+      "code": 6056,
+      "name": "InsufficientReputation",
+      "msg": "Reputation is below the badge tier's threshold"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidOssProgram",
+      "msg": "Invalid OSS Program"
+    },
+    {
+      "code": 6058,
+      "name": "MinterNotActive",
+      "msg": "Minter is not active"
+    },
+    {
+      "code": 6059,
+      "name": "MinterEmpty",
+      "msg": "Minter is empty"
+    },
+    {
+      "code": 6060,
+      "name": "MinterNotStarted",
+      "msg": "Minter has not started"
+    },
+    {
+      "code": 6061,
+      "name": "MinterStartTimeInPast",
+      "msg": "Cannot start minter in the past"
+    },
+    {
+      "code": 6062,
+      "name": "MinterTooManyItems",
+      "msg": "Minter cannot have more items than the current epoch"
+    }
+  ]
+};
+
+export const IDL: Epochs = {
+  "version": "0.1.0",
+  "name": "epochs",
+  "instructions": [
+    {
+      "name": "initializeConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Only the bootstrap authority can create the config.",
+            "Admin rights are handed to `args.admin` from then on."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializePointsTable",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PointsTableArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePointsTable",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "PointsTableArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeLeaderboard",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncLeaderboard",
+      "accounts": [
+        {
+          "name": "reputation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay parameters"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createGroup",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createBadgeGroup",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "badgeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": "BadgeTier"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateBadgeTiers",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "badgeConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": "BadgeTier"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintBadge",
+      "accounts": [
+        {
+          "name": "contributor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "reputation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay parameters"
+          ]
+        },
+        {
+          "name": "badgeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "badge",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "One badge per contributor; `upgrade_badge` replaces its asset"
+          ]
+        },
+        {
+          "name": "badgeAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "badgeGroup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tier",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "upgradeBadge",
+      "accounts": [
+        {
+          "name": "contributor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "reputation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay parameters"
+          ]
+        },
+        {
+          "name": "badgeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "badge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currentBadgeAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "badgeAsset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "badgeGroup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tier",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createEpoch",
+      "accounts": [
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidHistory",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Fixed-size ring buffer of the auction's most recent bids"
+          ]
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "auctionBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Anybody can bid on an auction.",
+            "No constraits--just need to be a signer"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "We will update the auction PDA based on the bid",
+            "Seeded on user-input epoch (auction end slot is verified in program)",
+            "See state/auction.rs for more details"
+          ]
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The auction escrow account will hold the funds for the auction"
+          ]
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Tracks what the escrow owes; new lamports from the bidder are added to it"
+          ]
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "PDA that tracks lamports the bidder can withdraw or re-use",
+            "Seeded on user's pubkey",
+            "See state/bid_credit.rs for more details"
+          ]
+        },
+        {
+          "name": "bidDeposit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "PDA that tracks what the bidder has escrowed in this auction",
+            "Seeded on user-input epoch and user's pubkey",
+            "See state/bid_deposit.rs for more details"
+          ]
+        },
+        {
+          "name": "bidHistory",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The high bid after every bid is appended here so clients can show the bid ladder"
+          ]
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "PDA that will store the reputation for the user",
+            "Seeded on user's pubkey",
+            "Need to use `init_if_needed` bc we are not sure if the user has a reputation account",
+            "See state/reputation.rs for more details"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay and streak parameters"
+          ]
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "PDA that receives the referral fee if this bid wins, which the referrer withdraws like any bid credit",
+            "Seeded on the referrer's pubkey. Required with `referrer`"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidMint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Holds the tokens of the auction's live bids. Owned by the auction PDA",
+            "Created by the first bid on the auction"
+          ]
+        },
+        {
+          "name": "tokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "PDA that tracks tokens of the bid mint the bidder can withdraw, credited when their deposit is released",
+            "Seeded on the bid mint and user's pubkey",
+            "See state/token_credit.rs for more details"
+          ]
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Holds the tokens owed to all `TokenCredit`s of the bid mint. Owned by the authority PDA",
+            "Created by the first bid in the mint"
+          ]
+        },
+        {
+          "name": "referrerTokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token equivalent of `referrer_credit`. Required with `referrer`"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "bidAmount",
+          "type": "u64"
+        },
+        {
+          "name": "useCredit",
+          "type": "bool"
+        },
+        {
+          "name": "maxAmount",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "allowlistProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "recipient",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "commitBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "One commitment per bidder per auction"
+          ]
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Receives the deposit back (less any forfeit) when the sealed bid is resolved"
+          ]
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reputation decay and streak parameters"
+          ]
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "depositLamports",
+          "type": "u64"
+        },
+        {
+          "name": "allowlistProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "revealBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "bidLamports",
+          "type": "u64"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveSealedBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sealedBid",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "buyNow",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Marks the buyer as a bidder, so they are counted in `Stats::unique_bidders` like any other bidder"
+          ]
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        },
+        {
+          "name": "allowlistProof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "recipient",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeAuction",
+      "accounts": [
+        {
+          "name": "closer",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Anybody can close a settled auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidHistory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "daoTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Only needed when the token escrow still holds tokens"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawCredit",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTokenCredit",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenCredit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseBidDeposit",
+      "accounts": [
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidDeposit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Created with the deposit, so it always exists"
+          ]
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Created with the deposit, so it always exists"
+          ]
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "verifyEscrow",
+      "accounts": [
+        {
+          "name": "auctionEscrow",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sweepExcess",
+      "accounts": [
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "auctionClaim",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the winner may name a new recipient when claiming)"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Credited with the referral fee of the winning bid. Required when `auction.referrer` is set",
+            "Created by the referred bid"
+          ]
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "daoTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Validated against `config.dao_treasury` when distributing"
+          ]
+        },
+        {
+          "name": "referrerTokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token equivalent of `referrer_credit`. Required when `auction.referrer` is set"
+          ]
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Receives the referral fee for `referrer_token_credit`. Required when `auction.referrer` is set"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "recipient",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferWin",
+      "accounts": [
+        {
+          "name": "winner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "newWinner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Must sign when paying a price"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "winnerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The seller's credit, receives the price"
+          ]
+        },
+        {
+          "name": "winnerDeposit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The seller's deposit, which backs the winning bid"
+          ]
+        },
+        {
+          "name": "newWinnerDeposit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Takes over the high bid from the seller's deposit"
+          ]
+        },
+        {
+          "name": "newWinnerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`claim` and `release_bid_deposit` need the new winner's credit and reputation accounts, so the seller creates them if needed"
+          ]
+        },
+        {
+          "name": "newWinnerReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        },
+        {
+          "name": "priceLamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleAuction",
+      "accounts": [
+        {
+          "name": "settler",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Anybody but the winner can settle"
+          ]
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrowLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stats",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Protocol-wide totals"
+          ]
+        },
+        {
+          "name": "winner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "winnerReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "settlerReputation",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "PDA that will store the reputation for the settler",
+            "Need to use `init_if_needed` bc we are not sure if the settler has a reputation account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrerCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Credited with the referral fee of the winning bid. Required when `auction.referrer` is set",
+            "Created by the referred bid"
+          ]
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenEscrow",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "daoTreasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Validated against `config.dao_treasury` when distributing"
+          ]
+        },
+        {
+          "name": "referrerTokenCredit",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token equivalent of `referrer_credit`. Required when `auction.referrer` is set"
+          ]
+        },
+        {
+          "name": "tokenCreditVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Receives the referral fee for `referrer_token_credit`. Required when `auction.referrer` is set"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleUnsoldAuction",
+      "accounts": [
+        {
+          "name": "settler",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "settlerReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pointsTable",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Points awarded for each action"
+          ]
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Top contributors by reputation, updated with the new reputation"
+          ]
+        },
+        {
+          "name": "daoTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawVaultAsset",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ossProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputEpoch",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateReputation",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "reputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "contributor",
+          "type": "publicKey"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "Auction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "AuctionState"
+            }
+          },
+          {
+            "name": "highBidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "highBidLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "params",
+            "docs": [
+              "Snapshot of `Config::auction_params` taken when the auction was created"
+            ],
+            "type": {
+              "defined": "AuctionParams"
+            }
+          },
+          {
+            "name": "endSlot",
+            "docs": [
+              "First slot at which bids are no longer accepted.",
+              "Starts at the first slot of the next epoch and can be pushed back by late bids."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "secondBidLamports",
+            "docs": [
+              "Second highest revealed bid (sealed auctions only), used for second-price settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startSlot",
+            "docs": [
+              "Slot the auction was created in, where a Dutch auction's price starts decaying"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proxyMaxLamports",
+            "docs": [
+              "Hidden maximum of the high bidder, fully escrowed (English auctions only).",
+              "The visible `high_bid_lamports` is only raised up to this when somebody bids against it."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Frontend that referred the leading bid (`Pubkey::default()` for none).",
+              "Paid `Config::referral_bps` of the price at settlement."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Wallet the asset is delivered to instead of the winner (`Pubkey::default()` for the winner).",
+              "Set with the leading bid or named by the winner at claim."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "openDeposits",
+            "docs": [
+              "`BidDeposit` accounts not released yet (English auctions only). The auction cannot be closed until this is 0"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unresolvedSealedBids",
+            "docs": [
+              "`SealedBid` commitments not resolved yet (sealed auctions only). The auction cannot be closed until this is 0"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BadgeConfig",
+      "docs": [
+        "Reputation tiers that can be shown as soulbound badges. Admin-controlled.",
+        "Singleton seeded on `BADGE_CONFIG_SEED`, created alongside the badge group."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tiers",
+            "docs": [
+              "Ordered from lowest to highest threshold; a badge's tier is its index here"
+            ],
+            "type": {
+              "vec": {
+                "defined": "BadgeTier"
+              }
+            }
+          },
+          {
+            "name": "mintedTiers",
+            "docs": [
+              "One past the highest tier any badge has been minted for; `tiers` can't shrink below it"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Badge",
+      "docs": [
+        "A contributor's current reputation badge. Seeded on the contributor's pubkey."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contributor",
+            "type": "publicKey"
+          },
+          {
+            "name": "asset",
+            "docs": [
+              "The soulbound Nifty asset, seeded on `BADGE_ASSET_SEED`, the contributor and the tier"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tier",
+            "docs": [
+              "Index into `BadgeConfig::tiers`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidCredit",
+      "docs": [
+        "Lamports owed to a bidder that are still held in the auction escrow (e.g., after being outbid).",
+        "Seeded on the bidder's pubkey. Can be withdrawn or applied toward a new bid."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
+            "type": "u64"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "hasBid",
+            "docs": [
+              "Set on the owner's first bid or won auction, so referrer-only credits are not counted as bidders"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidDeposit",
+      "docs": [
+        "What a bidder has escrowed in one English auction.",
+        "Seeded on the epoch and the bidder's pubkey. Outbidding somebody moves nothing: once their deposit",
+        "no longer backs the leading bid, anybody can `release_bid_deposit` it into their `BidCredit`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports (or base units of the bid mint) held in escrow for the bidder"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidHistory",
+      "docs": [
+        "The most recent bids on an epoch's auction.",
+        "Seeded on `BID_HISTORY_SEED` and the epoch. Zero-copy ring buffer: once full, each new bid",
+        "overwrites the oldest record, so clients can fetch the bid ladder in a single account read",
+        "(the oldest record is at `total_bids % CAPACITY` once the buffer has wrapped)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "totalBids",
+            "docs": [
+              "Bids ever recorded; the next write goes to `total_bids % CAPACITY`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "bids",
+            "type": {
+              "array": [
+                {
+                  "defined": "BidRecord"
+                },
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Config",
+      "docs": [
+        "Global, admin-controlled program settings.",
+        "Singleton seeded on `CONFIG_SEED`. Replaces the wallets and shares that used to be compiled in."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "daoTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "treasuryShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "CreatorShare"
+              }
+            }
+          },
+          {
+            "name": "royaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "auctionParams",
+            "docs": [
+              "Applied to auctions created from now on; live auctions keep their own snapshot"
+            ],
+            "type": {
+              "defined": "AuctionParams"
+            }
+          },
+          {
+            "name": "referralBps",
+            "docs": [
+              "Share of the sale price paid to the frontend that referred the winning bid, before the treasury/creator split"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "reputationParams",
+            "type": {
+              "defined": "ReputationParams"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "EscrowLedger",
+      "docs": [
+        "Solvency accounting for the shared auction escrow.",
+        "Singleton seeded on `ESCROW_LEDGER_SEED`. Tracks every lamport the escrow owes",
+        "(live high bids and unwithdrawn bid credit) so anything above that can be swept to the treasury."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalOutstandingLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Leaderboard",
+      "docs": [
+        "The top contributors by reputation, highest first.",
+        "Singleton seeded on `LEADERBOARD_SEED`. Updated whenever an instruction changes a contributor's reputation;",
+        "since decay is applied lazily, entries reflect each contributor's reputation as of their last update",
+        "and can be refreshed with `sync_leaderboard`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "len",
+            "docs": [
+              "Number of filled entries"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "LeaderboardEntry"
+                },
+                50
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PointsTable",
+      "docs": [
+        "Reputation points awarded for each action. Admin-controlled so points can be tuned and promotions run",
+        "without a program upgrade. Singleton seeded on `POINTS_TABLE_SEED`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "initiate",
+            "type": "u64"
+          },
+          {
+            "name": "bid",
+            "type": "u64"
+          },
+          {
+            "name": "win",
+            "type": "u64"
+          },
+          {
+            "name": "settle",
+            "type": "u64"
+          },
+          {
+            "name": "forceSettle",
+            "type": "u64"
+          },
+          {
+            "name": "bidLamportsPerPoint",
+            "docs": [
+              "Extra bid points for every this many lamports bid (0 to disable size-based points)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxBidSizePoints",
+            "docs": [
+              "Cap on the size-based bid points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "milestoneIntervalEpochs",
+            "docs": [
+              "Epochs divisible by this are milestones (0 to disable)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "milestoneMultiplierBps",
+            "docs": [
+              "Multiplier on all points earned on a milestone epoch's auction, in bps (20,000 = double points)"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Reputation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contributor",
+            "type": "publicKey"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lastActiveEpoch",
+            "docs": [
+              "Last epoch with a bid or initiation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "streak",
+            "docs": [
+              "Consecutive epochs with a bid or initiation, ending at `last_active_epoch`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastDecayEpoch",
+            "docs": [
+              "Epoch decay was last applied up to (0 until the account is first touched after decay was introduced)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initiations",
+            "docs": [
+              "Lifetime count and points (before decay) for each kind of action.",
+              "Accounts migrated from an older layout only count actions from the migration on."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initiationPoints",
+            "type": "u64"
+          },
+          {
+            "name": "bids",
+            "type": "u64"
+          },
+          {
+            "name": "bidPoints",
+            "type": "u64"
+          },
+          {
+            "name": "wins",
+            "type": "u64"
+          },
+          {
+            "name": "winPoints",
+            "type": "u64"
+          },
+          {
+            "name": "settles",
+            "type": "u64"
+          },
+          {
+            "name": "settlePoints",
+            "type": "u64"
+          },
+          {
+            "name": "totalBidLamports",
+            "docs": [
+              "Lamports offered across every accepted open bid in lamport auctions (sealed bids are hidden when committed)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBid",
+      "docs": [
+        "A bidder's commitment in a sealed-bid auction.",
+        "Seeded on the auction epoch and the bidder's pubkey. Closed by `resolve_sealed_bid` once the auction is over."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "commitment",
+            "docs": [
+              "keccak256(bid_lamports (u64 LE) || salt || bidder)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "depositLamports",
+            "docs": [
+              "Lamports escrowed with the commitment. The revealed bid can not exceed it."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "revealed",
+            "type": "bool"
+          },
+          {
+            "name": "bidLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Stats",
+      "docs": [
+        "Protocol-wide totals for dashboards.",
+        "Singleton seeded on `STATS_SEED`. Lamport totals only include lamport auctions.",
+        "`treasury_lamports` also includes forfeited sealed-bid deposits and swept escrow surplus."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionsCreated",
+            "type": "u64"
+          },
+          {
+            "name": "auctionsSettled",
+            "docs": [
+              "Auctions sold (claimed, settled or bought); unsold auctions are not counted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRaisedLamports",
+            "type": "u64"
+          },
+          {
+            "name": "treasuryLamports",
+            "type": "u64"
+          },
+          {
+            "name": "creatorLamports",
+            "type": "u64"
+          },
+          {
+            "name": "referralLamports",
+            "type": "u64"
+          },
+          {
+            "name": "highestPriceLamports",
+            "type": "u64"
+          },
+          {
+            "name": "highestPriceEpoch",
+            "type": "u64"
+          },
+          {
+            "name": "uniqueBidders",
+            "docs": [
+              "Wallets that have ever bid or taken over a win (counted on their `BidCredit`'s first bid)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenCredit",
+      "docs": [
+        "Tokens of one bid mint owed to a wallet (e.g., a released bid deposit), held in the mint's token credit vault.",
+        "Seeded on the mint and the owner's pubkey. Withdrawn by the owner with `withdraw_token_credit`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Base units of `mint`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     }
   ],
-  "accounts": [
+  "types": [
+    {
+      "name": "AuctionParams",
+      "docs": [
+        "Bidding rules for an auction. Configured globally and copied onto each auction at creation."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reservePriceLamports",
+            "docs": [
+              "No bid below this amount is accepted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minIncrementLamports",
+            "docs": [
+              "Every bid must beat the current high bid by at least this many lamports (must be non-zero)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minIncrementBps",
+            "docs": [
+              "Every bid must beat the current high bid by at least this share of it (0 to disable)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "extensionWindowSlots",
+            "docs": [
+              "A bid landing within this many slots of the end moves the end to `bid slot + window` (0 to disable)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "noBidPolicy",
+            "type": {
+              "defined": "NoBidPolicy"
+            }
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": "AuctionKind"
+            }
+          },
+          {
+            "name": "bidMint",
+            "docs": [
+              "SPL token that bids are placed in, or `None` for lamports (English auctions only).",
+              "For token auctions every amount in these params and on the auction is in the token's base units."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "claimGraceEpochs",
+            "docs": [
+              "Epochs after the auction ends (counting from the epoch it ended in) before anybody can force-settle it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "forfeitUnclaimedToDao",
+            "docs": [
+              "Whether a force-settled asset goes to the DAO treasury instead of the winner"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "gateWindowSlots",
+            "docs": [
+              "During the first `gate_window_slots` slots of the auction only gated bidders may bid (0 to disable)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gateMinReputation",
+            "docs": [
+              "Bidders with at least this much reputation pass the gate (0 to disable this rule)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gateAllowlistRoot",
+            "docs": [
+              "Merkle root of allowlisted wallets that pass the gate (all zeros to disable this rule)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "auction",
+      "name": "BadgeTier",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "epoch",
+            "name": "name",
+            "docs": [
+              "Shown in the badge name, e.g. \"Epochs Reputation: Gold\""
+            ],
+            "type": "string"
+          },
+          {
+            "name": "minReputation",
+            "docs": [
+              "Current (decayed) reputation needed to mint or upgrade to this tier"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidRecord",
+      "docs": [
+        "The standing high bid right after a bid was placed. When an existing proxy bid immediately beats the new bid,",
+        "this is the proxy holder at their raised price, so the ladder only shows prices that actually led."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "lamports",
             "type": "u64"
           },
           {
-            "name": "mint",
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatorShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
             "type": "publicKey"
           },
           {
-            "name": "state",
+            "name": "shareBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigArgs",
+      "docs": [
+        "Arguments for `initialize_config` and `update_config`.",
+        "Every field is overwritten on update, so clients should send the full desired config."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "daoTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "treasuryShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "creators",
             "type": {
-              "defined": "AuctionState"
+              "vec": {
+                "defined": "CreatorShare"
+              }
             }
           },
           {
-            "name": "highBidder",
+            "name": "royaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "auctionParams",
+            "type": {
+              "defined": "AuctionParams"
+            }
+          },
+          {
+            "name": "referralBps",
+            "type": "u16"
+          },
+          {
+            "name": "reputationParams",
+            "type": {
+              "defined": "ReputationParams"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LeaderboardEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contributor",
             "type": "publicKey"
           },
           {
-            "name": "highBidLamports",
+            "name": "reputation",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PointsTableArgs",
+      "docs": [
+        "Arguments for `initialize_points_table` and `update_points_table`.",
+        "Every field is overwritten on update, so clients should send the full desired table."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initiate",
             "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "bid",
+            "type": "u64"
+          },
+          {
+            "name": "win",
+            "type": "u64"
+          },
+          {
+            "name": "settle",
+            "type": "u64"
+          },
+          {
+            "name": "forceSettle",
+            "type": "u64"
+          },
+          {
+            "name": "bidLamportsPerPoint",
+            "type": "u64"
+          },
+          {
+            "name": "maxBidSizePoints",
+            "type": "u64"
+          },
+          {
+            "name": "milestoneIntervalEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "milestoneMultiplierBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationParams",
+      "docs": [
+        "How reputation decays and how streaks are rewarded. Configured globally on `Config`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "halfLifeEpochs",
+            "docs": [
+              "Reputation halves every this many epochs (0 to disable decay)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "streakBonusBps",
+            "docs": [
+              "Bonus on streak actions for every consecutive active epoch after the first, in bps of the base points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxStreakBonusBps",
+            "docs": [
+              "Cap on the streak bonus, in bps of the base points"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionKind",
+      "docs": [
+        "How bids are placed and the winner is priced"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "English"
+          },
+          {
+            "name": "Sealed",
+            "fields": [
+              {
+                "name": "revealWindowSlots",
+                "docs": [
+                  "Number of slots after `end_slot` during which bids can be revealed"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "forfeitBps",
+                "docs": [
+                  "Share of an unrevealed deposit sent to the DAO treasury"
+                ],
+                "type": "u16"
+              },
+              {
+                "name": "pricing",
+                "type": {
+                  "defined": "SealedBidPricing"
+                }
+              }
+            ]
+          },
+          {
+            "name": "Dutch",
+            "fields": [
+              {
+                "name": "startPrice",
+                "docs": [
+                  "Price in lamports at `start_slot`"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "floorPrice",
+                "docs": [
+                  "The price never decays below this"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "decayPerSlot",
+                "docs": [
+                  "Lamports taken off the price every slot"
+                ],
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBidPricing",
+      "docs": [
+        "What the winner of a sealed auction pays"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FirstPrice"
+          },
+          {
+            "name": "SecondPrice"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "UnClaimed"
+          },
+          {
+            "name": "Claimed"
+          },
+          {
+            "name": "Unsold"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NoBidPolicy",
+      "docs": [
+        "What happens to the asset of an auction that ends without any bids"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Treasury"
+          },
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Vault"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationAction",
+      "docs": [
+        "The action reputation is awarded for, used to keep the per-action breakdown"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Initiate"
+          },
+          {
+            "name": "Bid"
+          },
+          {
+            "name": "Win"
+          },
+          {
+            "name": "Settle"
           }
         ]
       }
+    }
+  ],
+  "events": [
+    {
+      "name": "EpochCreated",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "asset",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "initiator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BidPlaced",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bidAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "isHighBidder",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "highBidder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "highBid",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousHighBidder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "refundAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SealedBidCommitted",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositLamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "reputation",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "contributor",
-            "type": "publicKey"
-          },
-          {
-            "name": "reputation",
-            "type": "u64"
+      "name": "AuctionSettled",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referralAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "treasuryAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "creatorAmounts",
+          "type": {
+            "vec": "u64"
           },
-          {
-            "name": "initialized",
-            "type": "bool"
+          "index": false
+        },
+        {
+          "name": "bidMint",
+          "type": {
+            "option": "publicKey"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "timeMachineReceipt",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "claimer",
-            "type": "publicKey"
-          },
-          {
-            "name": "epoch",
-            "type": "u64"
+      "name": "AuctionUnsold",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "noBidPolicy",
+          "type": {
+            "defined": "NoBidPolicy"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "settler",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "timeMachine",
-      "docs": [
-        "An asset minter for retroactive epochs.",
-        "The machine will enable the generation of a fixed number of items",
-        "to represent previously occuring epochs."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "itemsAvailable",
-            "type": "u64"
-          },
-          {
-            "name": "itemsRedeemed",
-            "type": "u64"
-          },
-          {
-            "name": "startTime",
-            "type": "i64"
+      "name": "ReputationChanged",
+      "fields": [
+        {
+          "name": "contributor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "points",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "reputation",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WinTransferred",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "from",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "to",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "priceLamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuctionClosed",
+      "fields": [
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "state",
+          "type": {
+            "defined": "AuctionState"
           },
-          {
-            "name": "active",
-            "type": "bool"
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidMint",
+          "type": {
+            "option": "publicKey"
           },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "AuctionState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "UnClaimed"
+      "name": "BadgeMinted",
+      "fields": [
+        {
+          "name": "contributor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "asset",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tier",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "previousAsset",
+          "type": {
+            "option": "publicKey"
           },
-          {
-            "name": "Claimed"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -1058,76 +7326,296 @@ export const IDL: Epochs = {
     },
     {
       "code": 6004,
-      "name": "BidTooLow",
-      "msg": "Bid does not meet minimum bid threshold"
+      "name": "BidBelowReserve",
+      "msg": "Bid is below the auction's reserve price"
     },
     {
       "code": 6005,
-      "name": "InvalidPreviousBidder",
-      "msg": "Previous bidder passed does not match the current high bidder"
+      "name": "BidBelowMinimumIncrement",
+      "msg": "Bid does not beat the high bid by the minimum increment"
     },
     {
       "code": 6006,
+      "name": "BidBelowPercentageIncrement",
+      "msg": "Bid does not beat the high bid by the minimum percentage increment"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidMaxBid",
+      "msg": "Maximum bid must be at least the bid amount"
+    },
+    {
+      "code": 6008,
+      "name": "BidderNotAllowed",
+      "msg": "Only allowlisted or reputable bidders may bid during the gate window"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidPreviousBidder",
+      "msg": "Previous bidder credit passed does not match the current high bidder"
+    },
+    {
+      "code": 6010,
       "name": "InvalidWinner",
       "msg": "Signer did not win the auction"
     },
     {
-      "code": 6007,
+      "code": 6011,
+      "name": "InvalidRecipient",
+      "msg": "Recipient does not match the auction's asset recipient"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidNewWinner",
+      "msg": "New winner must differ from the current winner and sign when paying a price"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidSettler",
+      "msg": "The winner must claim their own auction"
+    },
+    {
+      "code": 6014,
       "name": "AuctionAlreadyClaimed",
       "msg": "Auction has already been claimed"
     },
     {
-      "code": 6008,
+      "code": 6015,
+      "name": "AuctionEnded",
+      "msg": "Auction has ended"
+    },
+    {
+      "code": 6016,
+      "name": "AuctionNotEnded",
+      "msg": "Auction has not ended yet"
+    },
+    {
+      "code": 6017,
+      "name": "NoBids",
+      "msg": "Auction ended without any bids"
+    },
+    {
+      "code": 6018,
+      "name": "AuctionHasBids",
+      "msg": "Auction has bids and must be claimed or settled"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidAuctionKind",
+      "msg": "Instruction is not supported by this auction's kind"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidAuctionParams",
+      "msg": "Invalid auction parameters"
+    },
+    {
+      "code": 6021,
+      "name": "AuctionNotSettled",
+      "msg": "Auction must be claimed, settled or unsold before it can be closed"
+    },
+    {
+      "code": 6022,
+      "name": "OpenBidDeposits",
+      "msg": "Auction has bid deposits that have not been released"
+    },
+    {
+      "code": 6023,
+      "name": "UnresolvedSealedBids",
+      "msg": "Auction has sealed bids that have not been resolved"
+    },
+    {
+      "code": 6024,
+      "name": "GracePeriodNotOver",
+      "msg": "Claim grace period has not passed"
+    },
+    {
+      "code": 6025,
+      "name": "AssetNotInVault",
+      "msg": "Auction asset is not held in the program vault"
+    },
+    {
+      "code": 6026,
+      "name": "RevealNotOpen",
+      "msg": "Reveal window has not opened yet"
+    },
+    {
+      "code": 6027,
+      "name": "RevealClosed",
+      "msg": "Reveal window has closed"
+    },
+    {
+      "code": 6028,
+      "name": "BidAlreadyRevealed",
+      "msg": "Bid has already been revealed"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidReveal",
+      "msg": "Revealed bid does not match the commitment"
+    },
+    {
+      "code": 6030,
+      "name": "BidExceedsDeposit",
+      "msg": "Revealed bid exceeds the deposit"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidBidder",
+      "msg": "Bidder does not match the sealed bid"
+    },
+    {
+      "code": 6032,
+      "name": "PriceAboveMaximum",
+      "msg": "Current price is above the buyer's maximum price"
+    },
+    {
+      "code": 6033,
+      "name": "MissingTokenAccounts",
+      "msg": "Token auctions require the bid mint, token accounts and token program"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidTokenAccount",
+      "msg": "Token account does not match the auction's bid mint or owner"
+    },
+    {
+      "code": 6035,
+      "name": "TokenCreditNotSupported",
+      "msg": "Bid credit cannot be used in token auctions"
+    },
+    {
+      "code": 6036,
+      "name": "TokenAuctionNotSupported",
+      "msg": "Instruction is not supported for token auctions"
+    },
+    {
+      "code": 6037,
+      "name": "InsufficientCredit",
+      "msg": "Bid credit balance is too low"
+    },
+    {
+      "code": 6038,
+      "name": "NoCredit",
+      "msg": "No bid credit to withdraw"
+    },
+    {
+      "code": 6039,
+      "name": "BidStillLeading",
+      "msg": "Bid deposit still backs the leading bid"
+    },
+    {
+      "code": 6040,
+      "name": "EscrowInsolvent",
+      "msg": "Escrow holds fewer lamports than it owes"
+    },
+    {
+      "code": 6041,
+      "name": "NoEscrowSurplus",
+      "msg": "Escrow has no surplus to sweep"
+    },
+    {
+      "code": 6042,
       "name": "InvalidTreasury",
       "msg": "Invalid treasury account"
     },
     {
-      "code": 6009,
+      "code": 6043,
       "name": "InvalidCreator",
       "msg": "Invalid creator account"
     },
     {
-      "code": 6010,
+      "code": 6044,
+      "name": "InvalidReferrer",
+      "msg": "Referrer account is missing or does not match the auction's referrer"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidAdmin",
+      "msg": "Signer is not the config admin"
+    },
+    {
+      "code": 6046,
+      "name": "TooManyCreators",
+      "msg": "Too many creators"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidBasisPoints",
+      "msg": "Basis points are out of range or shares do not sum to 10,000"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidPointsTable",
+      "msg": "Milestone epochs need a non-zero multiplier"
+    },
+    {
+      "code": 6049,
       "name": "InvalidContributor",
       "msg": "Contributor does not match signer of the transaction"
     },
     {
-      "code": 6011,
+      "code": 6050,
+      "name": "InvalidReputationAccount",
+      "msg": "Account is not a reputation account"
+    },
+    {
+      "code": 6051,
       "name": "Overflow",
       "msg": "Integer overflow"
     },
     {
-      "code": 6012,
+      "code": 6052,
       "name": "Underflow",
       "msg": "Integer underflow"
     },
     {
-      "code": 6013,
+      "code": 6053,
+      "name": "InvalidBadgeTiers",
+      "msg": "Badge tiers must be 1-5 named tiers with increasing thresholds"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidBadgeTier",
+      "msg": "Badge tier does not exist or is not above the current badge"
+    },
+    {
+      "code": 6055,
+      "name": "MintedBadgeTierRemoved",
+      "msg": "Badge tiers cannot drop a tier that badges have been minted for"
+    },
+    {
+      "code": 6056,
+      "name": "InsufficientReputation",
+      "msg": "Reputation is below the badge tier's threshold"
+    },
+    {
+      "code": 6057,
       "name": "InvalidOssProgram",
       "msg": "Invalid OSS Program"
     },
     {
-      "code": 6014,
+      "code": 6058,
       "name": "MinterNotActive",
       "msg": "Minter is not active"
     },
     {
-      "code": 6015,
+      "code": 6059,
       "name": "MinterEmpty",
       "msg": "Minter is empty"
     },
     {
-      "code": 6016,
+      "code": 6060,
       "name": "MinterNotStarted",
       "msg": "Minter has not started"
     },
     {
-      "code": 6017,
+      "code": 6061,
       "name": "MinterStartTimeInPast",
       "msg": "Cannot start minter in the past"
     },
     {
-      "code": 6018,
+      "code": 6062,
       "name": "MinterTooManyItems",
       "msg": "Minter cannot have more items than the current epoch"
     }
@@ -1,3 +1,4 @@
+export * from './commitment';
 export * from './constants';
 export * from './deserialize';
 export * from './idl';
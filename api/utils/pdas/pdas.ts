@@ -68,26 +68,180 @@ function getNftMintPda(program: Program<any>, epoch: number) {
     return nftMint;
 }
 
-function getTimeMachinePda(program: Program<any>) {
-    const [minter] = PublicKey.findProgramAddressSync(
-        [Buffer.from(SEEDS.TIME_MACHINE)],
+function getConfigPda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.CONFIG)],
+        program.programId
+    );
+    return pda;
+}
+
+function getEscrowLedgerPda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.ESCROW_LEDGER)],
+        program.programId
+    );
+    return pda;
+}
+
+function getStatsPda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.STATS)],
+        program.programId
+    );
+    return pda;
+}
+
+function getPointsTablePda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.POINTS_TABLE)],
+        program.programId
+    );
+    return pda;
+}
+
+function getLeaderboardPda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.LEADERBOARD)],
+        program.programId
+    );
+    return pda;
+}
+
+function getVaultPda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.VAULT)],
+        program.programId
+    );
+    return pda;
+}
+
+function getEventAuthorityPda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.EVENT_AUTHORITY)],
+        program.programId
+    );
+    return pda;
+}
+
+function getBidCreditPda(bidder: PublicKey, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(SEEDS.BID_CREDIT),
+            bidder.toBuffer()
+        ],
+        program.programId
+    );
+    return pda;
+}
+
+function getBidDepositPda(epoch: number, bidder: PublicKey, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(SEEDS.BID_DEPOSIT),
+            numberBuffer(BigInt(epoch)),
+            bidder.toBuffer()
+        ],
+        program.programId
+    );
+    return pda;
+}
+
+function getBidHistoryPda(epoch: number, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(SEEDS.BID_HISTORY),
+            numberBuffer(BigInt(epoch))
+        ],
+        program.programId
+    );
+    return pda;
+}
+
+function getSealedBidPda(epoch: number, bidder: PublicKey, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(SEEDS.SEALED_BID),
+            numberBuffer(BigInt(epoch)),
+            bidder.toBuffer()
+        ],
+        program.programId
+    );
+    return pda;
+}
+
+function getAuctionTokenEscrowPda(epoch: number, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(SEEDS.AUCTION_TOKEN_ESCROW),
+            numberBuffer(BigInt(epoch))
+        ],
+        program.programId
+    );
+    return pda;
+}
+
+function getTokenCreditPda(mint: PublicKey, owner: PublicKey, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(SEEDS.TOKEN_CREDIT),
+            mint.toBuffer(),
+            owner.toBuffer()
+        ],
         program.programId
     );
-    return minter;
+    return pda;
 }
 
+function getTokenCreditVaultPda(mint: PublicKey, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(SEEDS.TOKEN_CREDIT_VAULT),
+            mint.toBuffer()
+        ],
+        program.programId
+    );
+    return pda;
+}
 
-function getTimeMachineReceiptPda(program: Program<any>, payer: PublicKey) {
-    const [minterClaim] = PublicKey.findProgramAddressSync(
+function getBadgeGroupPda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.BADGE_GROUP)],
+        program.programId
+    );
+    return pda;
+}
+
+function getBadgeConfigPda(program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEEDS.BADGE_CONFIG)],
+        program.programId
+    );
+    return pda;
+}
+
+function getBadgePda(contributor: PublicKey, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
         [
-            Buffer.from(SEEDS.TIME_MACHINE_RECEIPT),
-            payer.toBuffer()
+            Buffer.from(SEEDS.BADGE),
+            contributor.toBuffer()
         ],
         program.programId
     );
-    return minterClaim;
+    return pda;
 }
 
+function getBadgeAssetPda(contributor: PublicKey, tier: number, program: Program<any>) {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(SEEDS.BADGE_ASSET),
+            contributor.toBuffer(),
+            Uint8Array.from([tier])
+        ],
+        program.programId
+    );
+    return pda;
+}
 
 export {
     getAuctionPda,
@@ -96,6 +250,22 @@ export {
     getAuctionEscrowPda,
     getCollectionMintPda,
     getNftMintPda,
-    getTimeMachinePda,
-    getTimeMachineReceiptPda
+    getConfigPda,
+    getEscrowLedgerPda,
+    getStatsPda,
+    getPointsTablePda,
+    getLeaderboardPda,
+    getVaultPda,
+    getEventAuthorityPda,
+    getBidCreditPda,
+    getBidDepositPda,
+    getBidHistoryPda,
+    getSealedBidPda,
+    getAuctionTokenEscrowPda,
+    getTokenCreditPda,
+    getTokenCreditVaultPda,
+    getBadgeGroupPda,
+    getBadgeConfigPda,
+    getBadgePda,
+    getBadgeAssetPda
 };
\ No newline at end of file
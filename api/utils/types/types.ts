@@ -1,7 +1,15 @@
 import { PublicKey } from "@solana/web3.js";
+import { IdlTypes } from "@coral-xyz/anchor";
 import BN from "bn.js";
+import { Epochs } from "../idl";
 
-export type AuctionState = { unClaimed: {} } | { claimed: {} };
+export type AuctionState = { unClaimed: {} } | { claimed: {} } | { unsold: {} };
+
+export type AuctionParams = IdlTypes<Epochs>["AuctionParams"];
+export type AuctionKind = IdlTypes<Epochs>["AuctionKind"];
+export type ConfigArgs = IdlTypes<Epochs>["ConfigArgs"];
+export type PointsTableArgs = IdlTypes<Epochs>["PointsTableArgs"];
+export type BadgeTier = IdlTypes<Epochs>["BadgeTier"];
 
 export interface Auction {
     epoch: BN; 
@@ -10,4 +18,13 @@ export interface Auction {
     highBidder: PublicKey;
     highBidLamports: BN;
     bump: number;
+    params: AuctionParams;
+    endSlot: BN;
+    secondBidLamports: BN;
+    startSlot: BN;
+    proxyMaxLamports: BN;
+    referrer: PublicKey;
+    recipient: PublicKey;
+    openDeposits: BN;
+    unresolvedSealedBids: BN;
 }
\ No newline at end of file
@@ -1 +1,3 @@
-pub const AUTHORITY: &str = "AUTHbpGeSBi6tHHxnStySJhrwQfm5tLZQRcL2pCiVweL";
\ No newline at end of file
+/// Bootstrap key allowed to call `initialize_config`.
+/// After that, admin rights live in the on-chain `Config` account and can be rotated with `update_config`.
+pub const AUTHORITY: &str = "AUTHbpGeSBi6tHHxnStySJhrwQfm5tLZQRcL2pCiVweL";
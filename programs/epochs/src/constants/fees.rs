@@ -0,0 +1,2 @@
+/// All shares and fees are expressed in basis points of the sale price
+pub const BPS_DENOMINATOR: u64 = 10_000;
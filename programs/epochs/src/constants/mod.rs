@@ -1,11 +1,9 @@
 pub mod seeds;
-pub mod points;
 pub mod traits;
-pub mod royalties;
+pub mod fees;
 pub mod authority;
 
 pub use seeds::*;
-pub use points::*;
 pub use traits::*;
-pub use royalties::*;
-pub use authority::*;
\ No newline at end of file
+pub use fees::*;
+pub use authority::*;
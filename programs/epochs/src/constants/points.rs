@@ -1,8 +0,0 @@
-pub struct Points;
-
-impl Points {
-    pub const INITIATE: u64 = 50;
-    pub const SETTLE: u64 = 10;
-    pub const BID: u64 = 5;
-    pub const WIN: u64 = 25;
-}
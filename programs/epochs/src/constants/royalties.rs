@@ -1,10 +0,0 @@
-// TODO Replace with actual wallet addresses
-pub const CREATOR_WALLET_1 : &str = "MiLZAcWZgMYvFnZqzJ6XRj4h5r47bgX64Vt5gRnBvqv";
-pub const CREATOR_1_SHARE : u8 = 10;
-pub const CREATOR_WALLET_2 : &str = "MiLZAcWZgMYvFnZqzJ6XRj4h5r47bgX64Vt5gRnBvqv";
-pub const CREATOR_2_SHARE : u8 = 0;
-
-// TODO Replace with actual wallet addresses
-pub const DAO_TREASURY_WALLET : &str = "zuVfy5iuJNZKf5Z3piw5Ho4EpMxkg19i82oixjk1axe";
-pub const DAO_TREASURY_SHARE : u8 = 90;
- 
\ No newline at end of file
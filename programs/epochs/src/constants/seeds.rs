@@ -6,4 +6,21 @@ pub const AUTHORITY_SEED: &str = "Authority";
 pub const AUCTION_ESCROW_SEED: &str = "AuctionEscrow";
 pub const NFT_MINT_SEED: &str = "NftMint";
 pub const TIME_MACHINE_SEED: &str = "TimeMachine";
-pub const TIME_MACHINE_RECEIPT_SEED: &str = "TimeMachineReceipt";
\ No newline at end of file
+pub const TIME_MACHINE_RECEIPT_SEED: &str = "TimeMachineReceipt";
+pub const CONFIG_SEED: &str = "Config";
+pub const VAULT_SEED: &str = "Vault";
+pub const BID_CREDIT_SEED: &str = "BidCredit";
+pub const ESCROW_LEDGER_SEED: &str = "EscrowLedger";
+pub const SEALED_BID_SEED: &str = "SealedBid";
+pub const BID_HISTORY_SEED: &str = "BidHistory";
+pub const AUCTION_TOKEN_ESCROW_SEED: &str = "AuctionTokenEscrow";
+pub const STATS_SEED: &str = "Stats";
+pub const POINTS_TABLE_SEED: &str = "PointsTable";
+pub const BADGE_GROUP_SEED: &str = "BadgeGroup";
+pub const BADGE_CONFIG_SEED: &str = "BadgeConfig";
+pub const BADGE_SEED: &str = "Badge";
+pub const BADGE_ASSET_SEED: &str = "BadgeAsset";
+pub const LEADERBOARD_SEED: &str = "Leaderboard";
+pub const BID_DEPOSIT_SEED: &str = "BidDeposit";
+pub const TOKEN_CREDIT_SEED: &str = "TokenCredit";
+pub const TOKEN_CREDIT_VAULT_SEED: &str = "TokenCreditVault";
\ No newline at end of file
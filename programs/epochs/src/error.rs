@@ -20,28 +20,160 @@ pub enum EpochError {
 
     // Auction Errors
 
-    #[msg("Bid does not meet minimum bid threshold")]
-    BidTooLow,
+    #[msg("Bid is below the auction's reserve price")]
+    BidBelowReserve,
 
-    #[msg("Previous bidder passed does not match the current high bidder")]
+    #[msg("Bid does not beat the high bid by the minimum increment")]
+    BidBelowMinimumIncrement,
+
+    #[msg("Bid does not beat the high bid by the minimum percentage increment")]
+    BidBelowPercentageIncrement,
+
+    #[msg("Maximum bid must be at least the bid amount")]
+    InvalidMaxBid,
+
+    #[msg("Only allowlisted or reputable bidders may bid during the gate window")]
+    BidderNotAllowed,
+
+    #[msg("Previous bidder credit passed does not match the current high bidder")]
     InvalidPreviousBidder,
 
     #[msg("Signer did not win the auction")]
     InvalidWinner,
 
+    #[msg("Recipient does not match the auction's asset recipient")]
+    InvalidRecipient,
+
+    #[msg("New winner must differ from the current winner and sign when paying a price")]
+    InvalidNewWinner,
+
+    #[msg("The winner must claim their own auction")]
+    InvalidSettler,
+
     #[msg("Auction has already been claimed")]
     AuctionAlreadyClaimed,
 
+    #[msg("Auction has ended")]
+    AuctionEnded,
+
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+
+    #[msg("Auction ended without any bids")]
+    NoBids,
+
+    #[msg("Auction has bids and must be claimed or settled")]
+    AuctionHasBids,
+
+    #[msg("Instruction is not supported by this auction's kind")]
+    InvalidAuctionKind,
+
+    #[msg("Invalid auction parameters")]
+    InvalidAuctionParams,
+
+    #[msg("Auction must be claimed, settled or unsold before it can be closed")]
+    AuctionNotSettled,
+
+    #[msg("Auction has bid deposits that have not been released")]
+    OpenBidDeposits,
+
+    #[msg("Auction has sealed bids that have not been resolved")]
+    UnresolvedSealedBids,
+
+    #[msg("Claim grace period has not passed")]
+    GracePeriodNotOver,
+
+    #[msg("Auction asset is not held in the program vault")]
+    AssetNotInVault,
+
+    // Sealed Bid Errors
+
+    #[msg("Reveal window has not opened yet")]
+    RevealNotOpen,
+
+    #[msg("Reveal window has closed")]
+    RevealClosed,
+
+    #[msg("Bid has already been revealed")]
+    BidAlreadyRevealed,
+
+    #[msg("Revealed bid does not match the commitment")]
+    InvalidReveal,
+
+    #[msg("Revealed bid exceeds the deposit")]
+    BidExceedsDeposit,
+
+    #[msg("Bidder does not match the sealed bid")]
+    InvalidBidder,
+
+    // Dutch Auction Errors
+
+    #[msg("Current price is above the buyer's maximum price")]
+    PriceAboveMaximum,
+
+    // Token Auction Errors
+
+    #[msg("Token auctions require the bid mint, token accounts and token program")]
+    MissingTokenAccounts,
+
+    #[msg("Token account does not match the auction's bid mint or owner")]
+    InvalidTokenAccount,
+
+    #[msg("Bid credit cannot be used in token auctions")]
+    TokenCreditNotSupported,
+
+    #[msg("Instruction is not supported for token auctions")]
+    TokenAuctionNotSupported,
+
+    // Bid Credit Errors
+
+    #[msg("Bid credit balance is too low")]
+    InsufficientCredit,
+
+    #[msg("No bid credit to withdraw")]
+    NoCredit,
+
+    #[msg("Bid deposit still backs the leading bid")]
+    BidStillLeading,
+
+    // Escrow Errors
+
+    #[msg("Escrow holds fewer lamports than it owes")]
+    EscrowInsolvent,
+
+    #[msg("Escrow has no surplus to sweep")]
+    NoEscrowSurplus,
+
     #[msg("Invalid treasury account")]
     InvalidTreasury,
 
     #[msg("Invalid creator account")]
     InvalidCreator,
 
+    #[msg("Referrer account is missing or does not match the auction's referrer")]
+    InvalidReferrer,
+
+    // Config Errors
+
+    #[msg("Signer is not the config admin")]
+    InvalidAdmin,
+
+    #[msg("Too many creators")]
+    TooManyCreators,
+
+    #[msg("Basis points are out of range or shares do not sum to 10,000")]
+    InvalidBasisPoints,
+
+    #[msg("Milestone epochs need a non-zero multiplier")]
+    InvalidPointsTable,
+
     // Reputation Errors
 
     #[msg("Contributor does not match signer of the transaction")]
     InvalidContributor,
+
+    #[msg("Account is not a reputation account")]
+    InvalidReputationAccount,
     
     #[msg("Integer overflow")]
     Overflow,
@@ -49,6 +181,20 @@ pub enum EpochError {
     #[msg("Integer underflow")]
     Underflow,
 
+    // Badge Errors
+
+    #[msg("Badge tiers must be 1-5 named tiers with increasing thresholds")]
+    InvalidBadgeTiers,
+
+    #[msg("Badge tier does not exist or is not above the current badge")]
+    InvalidBadgeTier,
+
+    #[msg("Badge tiers cannot drop a tier that badges have been minted for")]
+    MintedBadgeTierRemoved,
+
+    #[msg("Reputation is below the badge tier's threshold")]
+    InsufficientReputation,
+
 
     // OSS Errors
 
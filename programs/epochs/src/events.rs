@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::{AuctionState, NoBidPolicy};
+
+// Emitted through a self-CPI (see utils/events.rs) so they survive log truncation.
+
+/// A new epoch's asset was minted and its auction opened.
+#[event]
+pub struct EpochCreated {
+    pub epoch: u64,
+    pub asset: Pubkey,
+    pub initiator: Pubkey,
+    pub start_slot: u64,
+    pub end_slot: u64,
+}
+
+/// A bid was accepted on an English auction.
+#[event]
+pub struct BidPlaced {
+    pub epoch: u64,
+    pub bidder: Pubkey,
+    pub bid_amount: u64,
+    /// Whether the bidder leads after this bid (an existing proxy bid may have beaten it right away)
+    pub is_high_bidder: bool,
+    pub high_bidder: Pubkey,
+    /// Visible high bid after this bid, including any automatic proxy raise
+    pub high_bid: u64,
+    /// High bidder before this bid (`Pubkey::default()` for the first bid)
+    pub previous_high_bidder: Pubkey,
+    /// Amount of `previous_high_bidder`'s deposit that no longer backs the leading bid
+    pub refund_amount: u64,
+    /// Referrer of the leading bid (`Pubkey::default()` for none)
+    pub referrer: Pubkey,
+    pub end_slot: u64,
+}
+
+/// A sealed bid was committed. The bid amount stays hidden until reveal.
+#[event]
+pub struct SealedBidCommitted {
+    pub epoch: u64,
+    pub bidder: Pubkey,
+    pub deposit_lamports: u64,
+}
+
+/// The asset was sent to the winner and the price split between the referrer, DAO treasury and creators.
+#[event]
+pub struct AuctionSettled {
+    pub epoch: u64,
+    pub winner: Pubkey,
+    /// Wallet that received the asset
+    pub recipient: Pubkey,
+    pub price: u64,
+    /// `Pubkey::default()` if the winning bid was not referred
+    pub referrer: Pubkey,
+    pub referral_amount: u64,
+    pub treasury_amount: u64,
+    /// In `Config::creators` order
+    pub creator_amounts: Vec<u64>,
+    /// `None` for lamport auctions
+    pub bid_mint: Option<Pubkey>,
+}
+
+/// An auction ended without bids and its asset was handled according to the no-bid policy.
+#[event]
+pub struct AuctionUnsold {
+    pub epoch: u64,
+    pub no_bid_policy: NoBidPolicy,
+    pub settler: Pubkey,
+}
+
+/// A contributor's reputation was incremented.
+#[event]
+pub struct ReputationChanged {
+    pub contributor: Pubkey,
+    pub points: u64,
+    pub reputation: u64,
+}
+
+/// The right to claim an ended auction was handed to another wallet.
+#[event]
+pub struct WinTransferred {
+    pub epoch: u64,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    /// Paid by `to` and credited to `from`
+    pub price_lamports: u64,
+}
+
+/// A settled auction was closed. Keeps the final result available to indexers once the account is gone.
+#[event]
+pub struct AuctionClosed {
+    pub epoch: u64,
+    pub mint: Pubkey,
+    pub state: AuctionState,
+    /// `Pubkey::default()` for unsold auctions
+    pub winner: Pubkey,
+    /// Price paid by the winner (0 for unsold auctions)
+    pub price: u64,
+    /// `None` for lamport auctions
+    pub bid_mint: Option<Pubkey>,
+}
+
+/// A contributor minted or upgraded their soulbound reputation badge.
+#[event]
+pub struct BadgeMinted {
+    pub contributor: Pubkey,
+    pub asset: Pubkey,
+    pub tier: u8,
+    /// The burned lower-tier badge, `None` for a first mint
+    pub previous_asset: Option<Pubkey>,
+}
@@ -1,37 +1,75 @@
-/// Represents the `AuctionBid` instruction, which allows anybody to bid on an auction.
-/// This instruction updates the auction state, transfers funds to the auction escrow account,
-/// returns funds to the previous high bidder, and updates the reputation of the bidder.
-/// 
-/// # Instruction Context
-/// 
-/// - `bidder`: The account of the bidder. Must be a signer.
-/// - `auction`: The auction account to be updated based on the bid. The account is seeded on the user-input epoch.
-/// - `auction_escrow`: The auction escrow account that holds the funds for the auction.
-/// - `high_bidder`: The account of the previous high bidder. Their funds will be returned to them.
-/// - `reputation`: The account that stores the reputation for the bidder. The account is seeded on the user's pubkey.
-/// - `system_program`: The system program account.
-/// 
-/// # Instruction Arguments
-///
-/// - `input_epoch`: The user-input epoch used to seed the auction account.
-/// - `bid_amount`: The amount of the bid in lamports.
-/// 
-/// # Errors
-/// 
-/// This instruction can return the following errors:
-/// 
-/// - `EpochError::InvalidPreviousBidder`: If the previous high bidder is not the expected account.
-/// - `EpochError::BidTooLow`: If the bid amount is lower than the minimum bid amount.
-/// - `EpochError::InvalidEpoch`: If the input epoch is invalid.
-/// 
+//! Represents the `AuctionBid` instruction, which allows anybody to bid on an auction.
+//! This instruction updates the auction state, transfers funds to the auction escrow account,
+//! and updates the reputation of the bidder. The previous high bidder's deposit stays in escrow until it is released
+//! to their `BidCredit` with `release_bid_deposit`, so a bid never needs any of their accounts.
+//! Bids placed within the auction's extension window push the auction's end slot back.
+//!
+//! A bid may carry a hidden maximum (proxy bid). The full maximum is escrowed and the program raises the
+//! bidder's visible bid automatically, only as far as needed to beat competing bids. Anything not used is
+//! released from the winner's deposit once the auction is claimed or settled. A bid that is immediately beaten by an existing proxy moves
+//! no funds and earns no reputation, and neither does the high bidder raising their own bid.
+//!
+//! # Instruction Context
+//!
+//! - `bidder`: The account of the bidder. Must be a signer.
+//! - `auction`: The auction account to be updated based on the bid. The account is seeded on the user-input epoch.
+//! - `auction_escrow`: The auction escrow account that holds the funds for the auction.
+//! - `escrow_ledger`: Solvency accounting for the auction escrow.
+//! - `stats`: Protocol-wide totals. Counts the bidder the first time they bid.
+//! - `bid_credit`: The bidder's credit ledger. Optionally applied toward this bid. The account is seeded on the user's pubkey.
+//! - `bid_deposit`: What the bidder has escrowed in this auction. Anything already in it (from an earlier bid that was outbid,
+//!   or the bid being raised) is returned to `bid_credit` first. The account is seeded on the user-input epoch and the user's pubkey.
+//! - `bid_history`: The auction's ring buffer of recent bids. Every bid appends the standing high bid it leaves behind, so a bid
+//!   immediately beaten by an existing proxy is recorded at the proxy's raised price. The account is seeded on the user-input epoch.
+//! - `reputation`: The account that stores the reputation for the bidder. The account is seeded on the user's pubkey.
+//! - `config`: The program config. Supplies the reputation decay and streak parameters.
+//! - `points_table`: The reputation points awarded for a bid.
+//! - `leaderboard`: The top contributors by reputation. Updated when the bidder earns reputation.
+//! - `referrer`: Optional frontend that referred the bid. Recorded on the auction while this bid leads and credited
+//!   `Config::referral_bps` of the price at settlement. Cannot be the bidder.
+//! - `referrer_credit`: The referrer's credit ledger, created if needed. Receives the referral fee. Required with `referrer`.
+//! - `system_program`: The system program account.
+//! - `bid_mint`, `bidder_token_account`, `auction_token_escrow`, `token_credit`, `token_credit_vault`, `referrer_token_credit`,
+//!   `authority`, `token_program`:
+//!   Token auctions only. Bids are transferred into the auction's token escrow, and the bidder's earlier deposit
+//!   is returned to their token account instead of `BidCredit`. Released deposits are credited to `token_credit`
+//!   and held in the mint's `token_credit_vault`. Omit for lamport auctions.
+//!
+//! # Instruction Arguments
+//!
+//! - `input_epoch`: The user-input epoch used to seed the auction account.
+//! - `bid_amount`: The amount of the bid in lamports (or base units of the bid mint for token auctions).
+//! - `use_credit`: Whether to pay for the bid with the bidder's existing credit before transferring new lamports.
+//!   Not supported in token auctions.
+//! - `max_amount`: Optional hidden maximum for proxy bidding. Must be at least `bid_amount`.
+//! - `allowlist_proof`: Merkle proof of the bidder's wallet against the auction's allowlist root.
+//!   Only needed during the auction's gate window by bidders without enough reputation; otherwise pass an empty proof.
+//! - `recipient`: Optional wallet to deliver the asset to if this bid wins (e.g., a vault). Defaults to the bidder, or when raising
+//!   one's own leading bid, to the recipient already named.
+//!
+//! # Errors
+//!
+//! This instruction can return the following errors:
+//!
+//! - `EpochError::BidBelowReserve`: If the bid amount is lower than the auction's reserve price.
+//! - `EpochError::BidBelowMinimumIncrement`: If the bid does not beat the high bid by the minimum increment.
+//! - `EpochError::BidBelowPercentageIncrement`: If the bid does not beat the high bid by the minimum percentage increment.
+//! - `EpochError::AuctionEnded`: If the auction's end slot has been reached.
+//! - `EpochError::InvalidMaxBid`: If `max_amount` is lower than `bid_amount`.
+//! - `EpochError::BidderNotAllowed`: If the gate window is open and the bidder has neither enough reputation nor a valid allowlist proof.
+//! - `EpochError::MissingTokenAccounts`: If a token auction is missing any of its token accounts.
+//! - `EpochError::TokenCreditNotSupported`: If `use_credit` is set in a token auction.
+//!
 
 use anchor_lang:: prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
-use crate::utils::validate::get_and_validate_epoch;
-use crate::{constants::*, EpochError};
+use crate::{constants::*, BidPlaced, EpochError, ReputationChanged};
 use crate::state::*;
+use crate::utils::{emit_cpi_event, transfer_from_token_escrow};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(input_epoch: u64)]
 pub struct AuctionBid<'info> {
@@ -42,13 +80,12 @@ pub struct AuctionBid<'info> {
     bidder: SystemAccount<'info>,
 
     /// We will update the auction PDA based on the bid
-    /// Seeded on user-input epoch (verified in program to be current epoch)
+    /// Seeded on user-input epoch (auction end slot is verified in program)
     /// See state/auction.rs for more details
     #[account(
         mut,
         seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
         bump = auction.bump,
-        has_one = high_bidder @ EpochError::InvalidPreviousBidder,
     )]
     auction: Account<'info, Auction>,
 
@@ -60,10 +97,53 @@ pub struct AuctionBid<'info> {
     )]
     pub auction_escrow: SystemAccount<'info>,
 
-    /// The previous high bidder will have their funds returned to them
-    /// CHECK: This is checks in the has_one constraint above
-    #[account(mut)]
-    high_bidder: AccountInfo<'info>,
+    /// Tracks what the escrow owes; new lamports from the bidder are added to it
+    #[account(
+        mut,
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    escrow_ledger: Account<'info, EscrowLedger>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    stats: Account<'info, Stats>,
+
+    /// PDA that tracks lamports the bidder can withdraw or re-use
+    /// Seeded on user's pubkey
+    /// See state/bid_credit.rs for more details
+    #[account(
+        init_if_needed,
+        seeds = [BID_CREDIT_SEED.as_bytes(), bidder.key().as_ref()],
+        bump,
+        payer = bidder,
+        space = BidCredit::get_size(),
+    )]
+    bid_credit: Account<'info, BidCredit>,
+
+    /// PDA that tracks what the bidder has escrowed in this auction
+    /// Seeded on user-input epoch and user's pubkey
+    /// See state/bid_deposit.rs for more details
+    #[account(
+        init_if_needed,
+        seeds = [BID_DEPOSIT_SEED.as_bytes(), &input_epoch.to_le_bytes(), bidder.key().as_ref()],
+        bump,
+        payer = bidder,
+        space = BidDeposit::get_size(),
+    )]
+    bid_deposit: Account<'info, BidDeposit>,
+
+    /// The high bid after every bid is appended here so clients can show the bid ladder
+    #[account(
+        mut,
+        seeds = [BID_HISTORY_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = bid_history.load()?.bump,
+    )]
+    bid_history: AccountLoader<'info, BidHistory>,
 
     /// PDA that will store the reputation for the user
     /// Seeded on user's pubkey
@@ -72,33 +152,257 @@ pub struct AuctionBid<'info> {
     #[account(
         init_if_needed,
         seeds = [REPUTATION_SEED.as_bytes(), bidder.key().as_ref()],
-        bump, 
+        bump,
         payer = bidder,
         space = Reputation::get_size(),
     )]
     reputation: Account<'info, Reputation>,
 
+    /// Reputation decay and streak parameters
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    /// Points awarded for each action
+    #[account(
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump = points_table.bump,
+    )]
+    points_table: Account<'info, PointsTable>,
+
+    /// Top contributors by reputation, updated with the new reputation
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump = leaderboard.load()?.bump,
+    )]
+    leaderboard: AccountLoader<'info, Leaderboard>,
+
+    /// CHECK: Optional frontend to credit with the bid. Only recorded, credited at settlement if this bid wins
+    #[account(
+        constraint = referrer.key() != bidder.key() @ EpochError::InvalidReferrer,
+    )]
+    referrer: Option<UncheckedAccount<'info>>,
+
+    /// PDA that receives the referral fee if this bid wins, which the referrer withdraws like any bid credit
+    /// Seeded on the referrer's pubkey. Required with `referrer`
+    #[account(
+        init_if_needed,
+        seeds = [BID_CREDIT_SEED.as_bytes(), referrer.as_ref().map(|referrer| referrer.key()).unwrap_or_default().as_ref()],
+        bump,
+        payer = bidder,
+        space = BidCredit::get_size(),
+    )]
+    referrer_credit: Option<Account<'info, BidCredit>>,
+
     system_program: Program<'info, System>,
+
+    // Token auctions only (`auction.params.bid_mint` is set), omit for lamport auctions
+
+    #[account(
+        address = auction.params.bid_mint.unwrap_or_default() @ EpochError::InvalidTokenAccount,
+    )]
+    bid_mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = bid_mint,
+        token::authority = bidder,
+    )]
+    bidder_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Holds the tokens of the auction's live bids. Owned by the auction PDA
+    /// Created by the first bid on the auction
+    #[account(
+        init_if_needed,
+        seeds = [AUCTION_TOKEN_ESCROW_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump,
+        payer = bidder,
+        token::mint = bid_mint,
+        token::authority = auction,
+    )]
+    auction_token_escrow: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// PDA that tracks tokens of the bid mint the bidder can withdraw, credited when their deposit is released
+    /// Seeded on the bid mint and user's pubkey
+    /// See state/token_credit.rs for more details
+    #[account(
+        init_if_needed,
+        seeds = [TOKEN_CREDIT_SEED.as_bytes(), auction.params.bid_mint.unwrap_or_default().as_ref(), bidder.key().as_ref()],
+        bump,
+        payer = bidder,
+        space = TokenCredit::get_size(),
+    )]
+    token_credit: Option<Box<Account<'info, TokenCredit>>>,
+
+    /// Holds the tokens owed to all `TokenCredit`s of the bid mint. Owned by the authority PDA
+    /// Created by the first bid in the mint
+    #[account(
+        init_if_needed,
+        seeds = [TOKEN_CREDIT_VAULT_SEED.as_bytes(), auction.params.bid_mint.unwrap_or_default().as_ref()],
+        bump,
+        payer = bidder,
+        token::mint = bid_mint,
+        token::authority = authority,
+    )]
+    token_credit_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Token equivalent of `referrer_credit`. Required with `referrer`
+    #[account(
+        init_if_needed,
+        seeds = [
+            TOKEN_CREDIT_SEED.as_bytes(),
+            auction.params.bid_mint.unwrap_or_default().as_ref(),
+            referrer.as_ref().map(|referrer| referrer.key()).unwrap_or_default().as_ref(),
+        ],
+        bump,
+        payer = bidder,
+        space = TokenCredit::get_size(),
+    )]
+    referrer_token_credit: Option<Box<Account<'info, TokenCredit>>>,
+
+    /// CHECK: Program Authority, owner of the token credit vault
+    #[account(
+        seeds = [AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    authority: Option<UncheckedAccount<'info>>,
+
+    token_program: Option<Program<'info, Token>>,
 }
 
-impl AuctionBid<'_> {
-    pub fn handler(&mut self, 
-        input_epoch: u64, 
+impl<'info> AuctionBid<'info> {
+    // The bumps are already passed as one struct; the rest are the instruction's own arguments
+    #[allow(clippy::too_many_arguments)]
+    pub fn handler(&mut self,
+        input_epoch: u64,
         bid_amount: u64,
-        escrow_bump: u8,
-        reputation_bump: u8
+        use_credit: bool,
+        max_amount: Option<u64>,
+        allowlist_proof: Vec<[u8; 32]>,
+        recipient: Option<Pubkey>,
+        bumps: &AuctionBidBumps,
     ) -> Result<()> {
-        get_and_validate_epoch(input_epoch)?;
+        require!(input_epoch == self.auction.epoch, EpochError::EpochMismatch);
+
+        let bidder = self.bidder.key();
+        self.bid_credit.init_if_needed(bidder, bumps.bid_credit);
+        if self.bid_credit.record_bid() {
+            self.stats.record_new_bidder()?;
+        }
+        if self.bid_deposit.init_if_needed(input_epoch, bidder, bumps.bid_deposit) {
+            self.auction.record_deposit_opened()?;
+        }
+        self.init_referrer_credits(bumps)?;
+
+        let clock = Clock::get()?;
+        // Checked before this bid's reputation is awarded
+        let reputation = self.reputation.current_reputation(&self.config.reputation_params, clock.epoch);
+        self.auction.validate_gate(&bidder, reputation, &allowlist_proof, clock.slot)?;
+        let outcome = self.auction.bid(
+            bidder,
+            bid_amount,
+            max_amount.unwrap_or(bid_amount),
+            self.referrer.as_ref().map(|referrer| referrer.key()).unwrap_or_default(),
+            recipient,
+            clock.slot,
+        )?;
+        self.bid_history.load_mut()?.append(self.auction.high_bidder, self.auction.high_bid_lamports, &clock)?;
+
+        if let Some(bid_mint) = self.auction.params.bid_mint {
+            require!(!use_credit, EpochError::TokenCreditNotSupported);
+            let Some(token_credit) = &mut self.token_credit else {
+                return err!(EpochError::MissingTokenAccounts);
+            };
+            token_credit.init_if_needed(bidder, bid_mint, bumps.token_credit);
+            self.return_deposit_tokens()?;
+            self.fund_bid_tokens(outcome.escrow_lamports)?;
+        } else {
+            self.return_deposit()?;
+            self.fund_bid(outcome.escrow_lamports, use_credit)?;
+        }
+        self.bid_deposit.amount = outcome.escrow_lamports;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            &BidPlaced {
+                epoch: self.auction.epoch,
+                bidder,
+                bid_amount,
+                is_high_bidder: outcome.is_high_bidder,
+                high_bidder: self.auction.high_bidder,
+                high_bid: self.auction.high_bid_lamports,
+                previous_high_bidder: outcome.previous_high_bidder,
+                refund_amount: outcome.refund_lamports,
+                referrer: self.auction.referrer,
+                end_slot: self.auction.end_slot,
+            },
+        )?;
+
+        // Every accepted bid counts towards the lifetime lamports bid. Only taking the lead earns points,
+        // so the high bidder can't farm reputation by raising their own bid
+        self.record_bid_lamports(bumps.reputation, bid_amount)?;
+        if outcome.is_high_bidder && outcome.previous_high_bidder != bidder {
+            let points = self.update_reputation(bid_amount)?;
+            emit_cpi_event(
+                &self.event_authority,
+                bumps.event_authority,
+                &ReputationChanged {
+                    contributor: bidder,
+                    points,
+                    reputation: self.reputation.reputation,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
 
-        self.transfer_funds_to_escrow(bid_amount)?;
-        self.transfer_funds_to_previous_high_bidder(escrow_bump)?;
-        self.update_auction_and_reputation(bid_amount, reputation_bump)?;
+    /// Creates the referrer's credit ledgers, so a referred bid that wins can be settled without any of their accounts
+    fn init_referrer_credits(&mut self, bumps: &AuctionBidBumps) -> Result<()> {
+        let Some(referrer) = self.referrer.as_ref().map(|referrer| referrer.key()) else {
+            return Ok(());
+        };
+        let Some(referrer_credit) = &mut self.referrer_credit else {
+            return err!(EpochError::InvalidReferrer);
+        };
+        referrer_credit.init_if_needed(referrer, bumps.referrer_credit);
 
+        if let Some(bid_mint) = self.auction.params.bid_mint {
+            let Some(referrer_token_credit) = &mut self.referrer_token_credit else {
+                return err!(EpochError::MissingTokenAccounts);
+            };
+            referrer_token_credit.init_if_needed(referrer, bid_mint, bumps.referrer_token_credit);
+        }
         Ok(())
     }
 
-    /// Transfers the bid amount from the bidder to the auction escrow account.
-    pub fn transfer_funds_to_escrow(&self, bid_amount: u64) -> Result<()>   {
+    /// Credits the bidder's earlier deposit in this auction back to them: it was either outbid,
+    /// or is replaced by the bid being raised. The lamports stay in escrow until withdrawn.
+    fn return_deposit(&mut self) -> Result<()> {
+        let amount = self.bid_deposit.amount;
+        self.bid_deposit.amount = 0;
+        self.bid_credit.credit(amount)
+    }
+
+    /// Covers the escrow with the bidder's credit (if requested) and transfers the rest to the auction escrow account.
+    fn fund_bid(&mut self, escrow_lamports: u64, use_credit: bool) -> Result<()> {
+        let from_credit = if use_credit {
+            escrow_lamports.min(self.bid_credit.lamports)
+        } else {
+            0
+        };
+        self.bid_credit.debit(from_credit)?;
+
+        let from_bidder = escrow_lamports - from_credit;
+        if from_bidder == 0 {
+            return Ok(());
+        }
+        self.escrow_ledger.deposit(from_bidder)?;
+
         transfer(
             CpiContext::new(
                 self.system_program.to_account_info(),
@@ -107,52 +411,84 @@ impl AuctionBid<'_> {
                     to: self.auction_escrow.to_account_info(),
                 },
             ),
-            bid_amount,
+            from_bidder,
         )?;
         Ok(())
     }
-    
-    /// Transfers the previous high bid amount from the auction escrow account to the previous high bidder.
-    pub fn transfer_funds_to_previous_high_bidder(&self, escrow_bump: u8) -> Result<()> {
-        require!(self.auction.high_bidder == self.high_bidder.key(), EpochError::InvalidPreviousBidder);
-        let previous_high_bid_lamports = self.auction.high_bid_lamports;
-
-        // Do this check b/c first bid is 0. 
-        if previous_high_bid_lamports == 0 {
+
+    /// Token auctions: returns the bidder's earlier deposit in this auction to their token account.
+    fn return_deposit_tokens(&mut self) -> Result<()> {
+        let amount = self.bid_deposit.amount;
+        self.bid_deposit.amount = 0;
+
+        let (_, bidder_token_account, auction_token_escrow, token_program) = self.token_accounts()?;
+        transfer_from_token_escrow(
+            &self.auction,
+            &auction_token_escrow.to_account_info(),
+            &bidder_token_account.to_account_info(),
+            &token_program.to_account_info(),
+            amount,
+        )
+    }
+
+    /// Token auctions: transfers the bid from the bidder's token account to the auction's token escrow.
+    fn fund_bid_tokens(&self, escrow_amount: u64) -> Result<()> {
+        if escrow_amount == 0 {
             return Ok(());
         }
-        
-        let bump = &[escrow_bump];
-        let seeds: &[&[u8]] = &[AUCTION_ESCROW_SEED.as_ref(), bump];
-        let signer_seeds = &[&seeds[..]];
 
-        transfer(
+        let (_, bidder_token_account, auction_token_escrow, token_program) = self.token_accounts()?;
+        token::transfer(
             CpiContext::new(
-                self.system_program.to_account_info(),
-                Transfer {
-                    from: self.auction_escrow.to_account_info(),
-                    to: self.high_bidder.to_account_info(),
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: bidder_token_account.to_account_info(),
+                    to: auction_token_escrow.to_account_info(),
+                    authority: self.bidder.to_account_info(),
                 },
-            ).with_signer(signer_seeds),
-            previous_high_bid_lamports,
-        )?;
-        Ok(())
+            ),
+            escrow_amount,
+        )
     }
-    
-    /// Updates the auction state and the reputation of the bidder.
-    pub fn update_auction_and_reputation(&mut self, bid_amount: u64, reputation_bump: u8) -> Result<()> {
-        let auction = & mut self.auction;
+
+    #[allow(clippy::type_complexity)]
+    fn token_accounts(&self) -> Result<(&Account<'info, Mint>, &Account<'info, TokenAccount>, &Account<'info, TokenAccount>, &Program<'info, Token>)> {
+        match (&self.bid_mint, &self.bidder_token_account, &self.auction_token_escrow, &self.token_program) {
+            (Some(bid_mint), Some(bidder_token_account), Some(auction_token_escrow), Some(token_program)) => {
+                Ok((bid_mint, bidder_token_account, auction_token_escrow, token_program))
+            }
+            _ => err!(EpochError::MissingTokenAccounts),
+        }
+    }
+
+    /// Token auction bids are not lamports, so they count as 0 towards lamport totals and size-based points
+    fn bid_lamports(&self, bid_amount: u64) -> u64 {
+        if self.auction.params.bid_mint.is_some() { 0 } else { bid_amount }
+    }
+
+    /// Adds `bid_amount` to the bidder's lifetime lamports bid, creating their reputation if needed
+    fn record_bid_lamports(&mut self, reputation_bump: u8, bid_amount: u64) -> Result<()> {
+        let bid_lamports = self.bid_lamports(bid_amount);
+        self.reputation.init_if_needed(self.bidder.key(), reputation_bump);
+        self.reputation.record_bid_lamports(bid_lamports)
+    }
+
+    /// Updates the reputation of the bidder, returning the points awarded.
+    /// In lamport auctions `bid_amount` sets the size-based bid points.
+    fn update_reputation(&mut self, bid_amount: u64) -> Result<u64> {
+        let points = self.points_table.bid_points(self.auction.epoch, self.bid_lamports(bid_amount))?;
         let reputation = & mut self.reputation;
         let bidder = self.bidder.key();
 
-        auction.bid(
+        let points = reputation.increment_with_streak(
+            ReputationAction::Bid,
+            points,
             bidder,
-            bid_amount
+            &self.config.reputation_params,
+            Clock::get()?.epoch,
         )?;
-
-        reputation.init_if_needed(bidder, reputation_bump);
-        reputation.increment_with_validation(Points::BID, bidder)?;
-        Ok(())
+        self.leaderboard.load_mut()?.update(bidder, reputation.reputation);
+        Ok(points)
     }
 
-}
\ No newline at end of file
+}
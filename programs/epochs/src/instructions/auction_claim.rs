@@ -1,22 +1,22 @@
-use anchor_lang::{prelude::*, solana_program::{instruction::Instruction, program::invoke_signed}, system_program::{transfer, Transfer}};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use nifty_asset::ID as NiftyAssetID;
 
-use nifty_asset::{
-    instructions::TransferBuilder,
-    ID as NiftyAssetID,
-};
-
-use std::str::FromStr;
-
-use crate::utils::verify_epoch_has_passed;
-use crate::{constants::*, EpochError};
+use crate::utils::{distribute_nft, emit_settlement_events, pay_rent, Distribution, SettlementFunds};
+use crate::{constants::*, EpochError, ReputationChanged};
 use crate::state::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(claim_epoch: u64)]
 pub struct AuctionClaim<'info> {
     #[account(mut, signer)]
     winner: SystemAccount<'info>,
 
+    /// CHECK: Receives the asset, validated against the auction's asset recipient in the handler
+    /// (the winner may name a new recipient when claiming)
+    recipient: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [AUCTION_SEED.as_bytes(), &claim_epoch.to_le_bytes()],
@@ -32,6 +32,21 @@ pub struct AuctionClaim<'info> {
     )]
     pub auction_escrow: SystemAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    escrow_ledger: Account<'info, EscrowLedger>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    stats: Account<'info, Stats>,
+
     #[account(
         mut,
         seeds = [REPUTATION_SEED.as_bytes(), winner.key().as_ref()],
@@ -41,23 +56,43 @@ pub struct AuctionClaim<'info> {
 
     system_program: Program<'info, System>,
 
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    /// Points awarded for each action
+    #[account(
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump = points_table.bump,
+    )]
+    points_table: Account<'info, PointsTable>,
+
+    /// Top contributors by reputation, updated with the new reputation
     #[account(
         mut,
-        address = Pubkey::from_str(DAO_TREASURY_WALLET).unwrap() @ EpochError::InvalidTreasury
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump = leaderboard.load()?.bump,
     )]
-    dao_treasury: SystemAccount<'info>,
+    leaderboard: AccountLoader<'info, Leaderboard>,
 
     #[account(
         mut,
-        address = Pubkey::from_str(CREATOR_WALLET_1).unwrap() @EpochError::InvalidCreator
+        address = config.dao_treasury @ EpochError::InvalidTreasury
     )]
-    creator1_wallet: SystemAccount<'info>,
+    dao_treasury: SystemAccount<'info>,
 
+    /// Credited with the referral fee of the winning bid. Required when `auction.referrer` is set
+    /// Created by the referred bid
     #[account(
         mut,
-        address = Pubkey::from_str(CREATOR_WALLET_2).unwrap() @EpochError::InvalidCreator
+        seeds = [BID_CREDIT_SEED.as_bytes(), auction.referrer.as_ref()],
+        bump = referrer_credit.bump,
     )]
-    creator2_wallet: SystemAccount<'info>,
+    referrer_credit: Option<Account<'info, BidCredit>>,
+
+    // Creator wallets are passed as remaining accounts, in the same order as `config.creators`
 
 
     /// CHECK: use bump seeds and validate on auction
@@ -89,133 +124,133 @@ pub struct AuctionClaim<'info> {
         bump,
     )]
     pub group: UncheckedAccount<'info>,
+
+    // Token auctions only (`auction.params.bid_mint` is set), omit for lamport auctions.
+    // Creator token accounts are then passed as remaining accounts instead of creator wallets
+
+    #[account(
+        mut,
+        seeds = [AUCTION_TOKEN_ESCROW_SEED.as_bytes(), &claim_epoch.to_le_bytes()],
+        bump,
+    )]
+    auction_token_escrow: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Validated against `config.dao_treasury` when distributing
+    #[account(mut)]
+    dao_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Token equivalent of `referrer_credit`. Required when `auction.referrer` is set
+    #[account(
+        mut,
+        seeds = [TOKEN_CREDIT_SEED.as_bytes(), auction.params.bid_mint.unwrap_or_default().as_ref(), auction.referrer.as_ref()],
+        bump = referrer_token_credit.bump,
+    )]
+    referrer_token_credit: Option<Box<Account<'info, TokenCredit>>>,
+
+    /// Receives the referral fee for `referrer_token_credit`. Required when `auction.referrer` is set
+    #[account(
+        mut,
+        seeds = [TOKEN_CREDIT_VAULT_SEED.as_bytes(), auction.params.bid_mint.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    token_credit_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    token_program: Option<Program<'info, Token>>,
 }
 
-impl AuctionClaim<'_> {
-    pub fn handler(&mut self, claim_epoch: u64, auction_escrow_bump:u8, authority_bump: u8) -> Result<()> {
+impl<'info> AuctionClaim<'info> {
+    pub fn handler(&mut self, claim_epoch: u64, recipient: Option<Pubkey>, auction_escrow_bump:u8, authority_bump: u8, event_authority_bump: u8, creator_wallets: &[AccountInfo<'info>]) -> Result<()> {
         self.validate_claim(claim_epoch)?;
+        if let Some(recipient) = recipient {
+            self.auction.recipient = recipient;
+        }
+        require_keys_eq!(self.recipient.key(), self.auction.asset_recipient(), EpochError::InvalidRecipient);
         self.pay_rent()?;
-        self.distribute_funds(auction_escrow_bump)?;
+        let distribution = self.distribute_funds(auction_escrow_bump, creator_wallets)?;
+        self.stats.record_sale(
+            self.auction.epoch,
+            self.auction.clearing_price(),
+            &distribution,
+            self.auction.params.bid_mint.is_none(),
+        )?;
         self.distribute_nft(authority_bump)?;
-        self.update_auction_and_reputation()?;
+        let points = self.update_auction_and_reputation()?;
+        self.emit_events(event_authority_bump, distribution, points)?;
         Ok(())
     }
 
     fn validate_claim (&self, claim_epoch: u64) -> Result<()> {
         require!(claim_epoch == self.auction.epoch, EpochError::EpochMismatch);
-        verify_epoch_has_passed(self.auction.epoch)?;
+        self.auction.validate_ended(Clock::get()?.slot)?;
         require!(self.auction.high_bidder == self.winner.key(), EpochError::InvalidWinner);
         require!(self.auction.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
+        require!(self.auction.has_bids(), EpochError::NoBids);
         Ok(())
     }
 
     fn pay_rent(&self) -> Result<()> {
-        let asset_lamports = self.asset.lamports();
-        let auction_lamports = self.auction.to_account_info().lamports();
-        // Use saturating add to prevent any weird scenario where somebody is sending lamports to these accounts
-        let refund_amount: u64 = asset_lamports.saturating_add(auction_lamports).min(300000);
-
-        transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                Transfer {
-                    from: self.winner.to_account_info(),
-                    to: self.authority.to_account_info(),
-                },
-            ),
-            refund_amount,
-        )?;
-
-        Ok(())
+        pay_rent(
+            &self.winner.to_account_info(),
+            &self.authority.to_account_info(),
+            &self.asset.to_account_info(),
+            &self.auction.to_account_info(),
+            &self.system_program.to_account_info(),
+        )
     }
 
-    fn distribute_funds(&self, escrow_bump: u8) -> Result<()> {
-        let escrow_balance: u64 = self.auction.high_bid_lamports;
-        let dao_treasury_lamports = escrow_balance.checked_mul(DAO_TREASURY_SHARE as u64).ok_or_else(|| EpochError::Overflow)? / 100;
-        let creator_lamports = escrow_balance.checked_sub(dao_treasury_lamports).ok_or_else(|| EpochError::Underflow)?;
-
-/* Currently only using one creator
-        let creator1_lamports = escrow_balance.checked_mul(CREATOR_1_SHARE as u64).ok_or_else(|| EpochError::Overflow)? / 100;
-        let creator2_lamports = escrow_balance.checked_sub(dao_treasury_lamports)
-            .ok_or_else(|| EpochError::Underflow)?
-            .checked_sub(creator1_lamports)
-            .ok_or_else(|| EpochError::Underflow)?; 
-*/
-
-        let bump = &[escrow_bump];
-        let seeds: &[&[u8]] = &[AUCTION_ESCROW_SEED.as_ref(), bump];
-        let signer_seeds = &[&seeds[..]];
-
-        transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                Transfer {
-                    from: self.auction_escrow.to_account_info(),
-                    to: self.dao_treasury.to_account_info(),
-                },
-            ).with_signer(signer_seeds),
-            dao_treasury_lamports,
-        )?;
-
-        transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                Transfer {
-                    from: self.auction_escrow.to_account_info(),
-                    to: self.creator1_wallet.to_account_info(),
-                },
-            ).with_signer(signer_seeds),
-            creator_lamports,
-        )?;
-
-/* Currently only using one creator
-         transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                Transfer {
-                    from: self.auction_escrow.to_account_info(),
-                    to: self.creator2_wallet.to_account_info(),
-                },
-            ).with_signer(signer_seeds),
-            creator2_lamports,
-        )?; 
-*/
-
-        Ok(())
+    fn distribute_funds(&mut self, escrow_bump: u8, creator_wallets: &[AccountInfo<'info>]) -> Result<Distribution> {
+        SettlementFunds {
+            config: &self.config,
+            auction: &self.auction,
+            escrow_ledger: &mut self.escrow_ledger,
+            auction_escrow: self.auction_escrow.as_ref(),
+            dao_treasury: self.dao_treasury.as_ref(),
+            referrer_credit: self.referrer_credit.as_mut(),
+            system_program: self.system_program.as_ref(),
+            auction_token_escrow: self.auction_token_escrow.as_deref(),
+            dao_treasury_token_account: self.dao_treasury_token_account.as_deref(),
+            referrer_token_credit: self.referrer_token_credit.as_deref_mut(),
+            token_credit_vault: self.token_credit_vault.as_deref(),
+            token_program: self.token_program.as_ref().map(|token_program| token_program.as_ref()),
+        }
+        .distribute(escrow_bump, creator_wallets)
     }
 
-
     fn distribute_nft(&self, authority_bump: u8) -> Result<()> {
-        let bump = &[authority_bump];
-        let seeds: &[&[u8]] = &[AUTHORITY_SEED.as_ref(), bump];
-        let signer_seeds = &[&seeds[..]];
-
-        let account_infos = vec![
-            self.asset.to_account_info(),
-            self.authority.to_account_info(),
-            self.winner.to_account_info(),
-            self.group.to_account_info(),
-            self.oss_program.to_account_info(),
-        ];
-
-        let transfer_ix: Instruction = TransferBuilder::new()
-            .asset(self.asset.key())
-            .signer(self.authority.key())
-            .recipient(self.winner.key())
-            .group(Some(self.group.key()))
-            .instruction();
-
-        invoke_signed(&transfer_ix, &account_infos, signer_seeds)?;
-
-        Ok(())
+        distribute_nft(&self.asset, &self.authority, &self.recipient, &self.group, &self.oss_program, authority_bump)
     }
 
-
-    fn update_auction_and_reputation(&mut self) -> Result<()> {
+    /// Returns the points awarded to the winner
+    fn update_auction_and_reputation(&mut self) -> Result<u64> {
+        let epoch = Clock::get()?.epoch;
+        let winner = self.winner.key();
+        let points = self.points_table.win_points(self.auction.epoch)?;
         let auction = & mut self.auction;
         let reputation = & mut self.reputation;
         auction.claim()?;
-        reputation.increment_with_validation(Points::WIN, self.winner.key())?;
-        Ok(())
+        let points = reputation.increment_with_validation(
+            ReputationAction::Win,
+            points,
+            winner,
+            &self.config.reputation_params,
+            epoch,
+        )?;
+        self.leaderboard.load_mut()?.update(winner, reputation.reputation);
+        Ok(points)
     }
-}
\ No newline at end of file
+
+    fn emit_events(&self, event_authority_bump: u8, distribution: Distribution, points: u64) -> Result<()> {
+        emit_settlement_events(
+            &self.event_authority,
+            event_authority_bump,
+            &self.auction,
+            self.recipient.key(),
+            distribution,
+            &[ReputationChanged {
+                contributor: self.auction.high_bidder,
+                points,
+                reputation: self.reputation.reputation,
+            }],
+        )
+    }
+}
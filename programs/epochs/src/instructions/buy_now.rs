@@ -0,0 +1,244 @@
+//! Represents the `BuyNow` instruction, which settles a Dutch auction immediately at its current price.
+//! The price is routed through the auction escrow and split between the DAO treasury and creators,
+//! the asset is transferred to the buyer (or the recipient they name), and the buyer receives the `PointsTable` win points.
+//!
+//! # Instruction Arguments
+//!
+//! - `input_epoch`: The user-input epoch used to seed the auction account.
+//! - `max_price`: The most the buyer is willing to pay (protects against a stale price).
+//! - `allowlist_proof`: Merkle proof of the buyer's wallet against the auction's allowlist root.
+//!   Only needed during the auction's gate window by buyers without enough reputation; otherwise pass an empty proof.
+//! - `recipient`: Optional wallet to deliver the asset to (e.g., a vault). Defaults to the buyer.
+//!
+//! # Errors
+//!
+//! - `EpochError::InvalidAuctionKind`: If the auction is not a Dutch auction.
+//! - `EpochError::AuctionEnded`: If the auction's end slot has been reached.
+//! - `EpochError::AuctionAlreadyClaimed`: If the asset has already been bought.
+//! - `EpochError::PriceAboveMaximum`: If the current price is above `max_price`.
+//! - `EpochError::BidderNotAllowed`: If the gate window is open and the buyer has neither enough reputation nor a valid allowlist proof.
+//! - `EpochError::InvalidRecipient`: If the recipient account does not match `recipient` (or the buyer when none is named).
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use nifty_asset::ID as NiftyAssetID;
+
+use crate::utils::{distribute_funds, distribute_nft, emit_cpi_event, pay_rent, Distribution};
+use crate::{constants::*, AuctionSettled, EpochError, ReputationChanged};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(input_epoch: u64)]
+pub struct BuyNow<'info> {
+    #[account(mut, signer)]
+    buyer: SystemAccount<'info>,
+
+    /// CHECK: Receives the asset, validated against the auction's asset recipient in the handler
+    recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_ESCROW_SEED.as_ref()],
+        bump
+    )]
+    pub auction_escrow: SystemAccount<'info>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    stats: Account<'info, Stats>,
+
+    /// Marks the buyer as a bidder, so they are counted in `Stats::unique_bidders` like any other bidder
+    #[account(
+        init_if_needed,
+        seeds = [BID_CREDIT_SEED.as_bytes(), buyer.key().as_ref()],
+        bump,
+        payer = buyer,
+        space = BidCredit::get_size(),
+    )]
+    bid_credit: Account<'info, BidCredit>,
+
+    #[account(
+        init_if_needed,
+        seeds = [REPUTATION_SEED.as_bytes(), buyer.key().as_ref()],
+        bump,
+        payer = buyer,
+        space = Reputation::get_size(),
+    )]
+    reputation: Account<'info, Reputation>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    /// Points awarded for each action
+    #[account(
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump = points_table.bump,
+    )]
+    points_table: Account<'info, PointsTable>,
+
+    /// Top contributors by reputation, updated with the new reputation
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump = leaderboard.load()?.bump,
+    )]
+    leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        address = config.dao_treasury @ EpochError::InvalidTreasury
+    )]
+    dao_treasury: SystemAccount<'info>,
+
+    // Creator wallets are passed as remaining accounts, in the same order as `config.creators`
+
+    /// CHECK: use bump seeds and validate on auction
+    #[account(
+        mut,
+        seeds = [NFT_MINT_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump,
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: use bump seeds and validate on auction
+    #[account(
+        mut,
+        seeds = [AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    pub authority: AccountInfo<'info>,
+
+    /// CHECK: use address constraint
+    #[account(
+        address = NiftyAssetID @ EpochError::InvalidOssProgram
+    )]
+    pub oss_program: UncheckedAccount<'info>,
+
+    /// CHECK: Group Asset
+    #[account(
+        mut,
+        seeds = [COLLECTION_SEED.as_bytes()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+}
+
+impl<'info> BuyNow<'info> {
+    pub fn handler(
+        &mut self,
+        input_epoch: u64,
+        max_price: u64,
+        allowlist_proof: Vec<[u8; 32]>,
+        recipient: Option<Pubkey>,
+        bumps: &BuyNowBumps,
+        creator_wallets: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(input_epoch == self.auction.epoch, EpochError::EpochMismatch);
+
+        let buyer = self.buyer.key();
+        let clock = Clock::get()?;
+        let reputation = self.reputation.current_reputation(&self.config.reputation_params, clock.epoch);
+        self.auction.validate_gate(&buyer, reputation, &allowlist_proof, clock.slot)?;
+        let price = self.auction.buy_now(buyer, recipient, clock.slot)?;
+        require!(price <= max_price, EpochError::PriceAboveMaximum);
+        require_keys_eq!(self.recipient.key(), self.auction.asset_recipient(), EpochError::InvalidRecipient);
+        self.bid_credit.init_if_needed(buyer, bumps.bid_credit);
+        if self.bid_credit.record_bid() {
+            self.stats.record_new_bidder()?;
+        }
+
+        pay_rent(
+            &self.buyer.to_account_info(),
+            &self.authority.to_account_info(),
+            &self.asset.to_account_info(),
+            &self.auction.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+        let distribution = self.pay_and_distribute_funds(price, bumps.auction_escrow, creator_wallets)?;
+        self.stats.record_sale(self.auction.epoch, price, &distribution, true)?;
+        self.distribute_nft(bumps.authority)?;
+
+        let points = self.points_table.win_points(self.auction.epoch)?;
+        self.reputation.init_if_needed(buyer, bumps.reputation);
+        let points = self.reputation.increment_with_validation(
+            ReputationAction::Win,
+            points,
+            buyer,
+            &self.config.reputation_params,
+            clock.epoch,
+        )?;
+        self.leaderboard.load_mut()?.update(buyer, self.reputation.reputation);
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            &AuctionSettled {
+                epoch: self.auction.epoch,
+                winner: buyer,
+                recipient: self.recipient.key(),
+                price,
+                referrer: Pubkey::default(),
+                referral_amount: distribution.referral_amount,
+                treasury_amount: distribution.treasury_amount,
+                creator_amounts: distribution.creator_amounts,
+                bid_mint: None,
+            },
+        )?;
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            &ReputationChanged {
+                contributor: buyer,
+                points,
+                reputation: self.reputation.reputation,
+            },
+        )
+    }
+
+    /// The price passes through the escrow and straight back out again,
+    /// so the escrow ledger is unaffected.
+    fn pay_and_distribute_funds(&self, price: u64, escrow_bump: u8, creator_wallets: &[AccountInfo<'info>]) -> Result<Distribution> {
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.buyer.to_account_info(),
+                    to: self.auction_escrow.to_account_info(),
+                },
+            ),
+            price,
+        )?;
+
+        distribute_funds(
+            &self.config,
+            price,
+            &self.auction_escrow.to_account_info(),
+            &self.dao_treasury.to_account_info(),
+            creator_wallets,
+            &self.system_program.to_account_info(),
+            escrow_bump,
+        )
+    }
+
+    fn distribute_nft(&self, authority_bump: u8) -> Result<()> {
+        distribute_nft(&self.asset, &self.authority, &self.recipient, &self.group, &self.oss_program, authority_bump)
+    }
+}
@@ -0,0 +1,144 @@
+//! Represents the `CloseAuction` instruction, which closes the account of an auction that has been
+//! claimed, settled or resolved as unsold, together with its `BidHistory` and, for token auctions, its token escrow.
+//! Tokens sent to the escrow outside of a bid are swept to the DAO treasury first. Rent goes back to the authority PDA.
+//! The epoch initiator paid most of the auction's rent; the authority refunded them at most 300000 lamports of it
+//! and is reimbursed for that refund at claim.
+//! The final result is emitted as an `AuctionClosed` event so indexers keep the price and winner.
+//!
+//! # Errors
+//!
+//! - `EpochError::AuctionNotSettled`: If the auction has not been claimed, settled or resolved as unsold.
+//! - `EpochError::OpenBidDeposits`: If any bid deposit has not been released yet (see `release_bid_deposit`).
+//! - `EpochError::UnresolvedSealedBids`: If any sealed bid has not been resolved yet (see `resolve_sealed_bid`).
+//! - `EpochError::MissingTokenAccounts`: If a token auction with bids is missing its token escrow or token program,
+//!   or the DAO treasury token account when the escrow still holds tokens.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::utils::{emit_cpi_event, transfer_from_token_escrow};
+use crate::{constants::*, AuctionClosed, EpochError};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(input_epoch: u64)]
+pub struct CloseAuction<'info> {
+    /// Anybody can close a settled auction
+    closer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = auction.bump,
+        close = authority,
+    )]
+    auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [BID_HISTORY_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = bid_history.load()?.bump,
+        close = authority,
+    )]
+    bid_history: AccountLoader<'info, BidHistory>,
+
+    /// CHECK: Program Authority, receives the rent
+    #[account(
+        mut,
+        seeds = [AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    // Token auctions with bids only (`auction.params.bid_mint` is set), omit otherwise
+
+    #[account(
+        mut,
+        seeds = [AUCTION_TOKEN_ESCROW_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump,
+    )]
+    auction_token_escrow: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Only needed when the token escrow still holds tokens
+    #[account(
+        mut,
+        token::mint = auction.params.bid_mint.unwrap_or_default(),
+        token::authority = config.dao_treasury,
+    )]
+    dao_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    token_program: Option<Program<'info, Token>>,
+}
+
+impl CloseAuction<'_> {
+    pub fn handler(&self, input_epoch: u64, event_authority_bump: u8) -> Result<()> {
+        let auction = &self.auction;
+        require!(input_epoch == auction.epoch, EpochError::EpochMismatch);
+        require!(auction.state != AuctionState::UnClaimed, EpochError::AuctionNotSettled);
+        // Released deposits need the auction account to tell whether they still back the winning bid
+        require!(auction.open_deposits == 0, EpochError::OpenBidDeposits);
+        // Resolving a sealed bid needs the auction account to tell whether it won
+        require!(auction.unresolved_sealed_bids == 0, EpochError::UnresolvedSealedBids);
+        // The token escrow is created by the first bid
+        if auction.params.bid_mint.is_some() && auction.has_bids() {
+            self.close_token_escrow()?;
+        }
+
+        let sold = auction.state == AuctionState::Claimed;
+        emit_cpi_event(
+            &self.event_authority,
+            event_authority_bump,
+            &AuctionClosed {
+                epoch: auction.epoch,
+                mint: auction.mint,
+                state: auction.state,
+                winner: if sold { auction.high_bidder } else { Pubkey::default() },
+                price: if sold { auction.clearing_price() } else { 0 },
+                bid_mint: auction.params.bid_mint,
+            },
+        )
+    }
+
+    /// Sweeps any stray tokens to the DAO treasury and closes the auction's token escrow, returning its rent to the authority PDA.
+    fn close_token_escrow(&self) -> Result<()> {
+        let (Some(auction_token_escrow), Some(token_program)) = (&self.auction_token_escrow, &self.token_program) else {
+            return err!(EpochError::MissingTokenAccounts);
+        };
+
+        if auction_token_escrow.amount > 0 {
+            let Some(dao_treasury_token_account) = &self.dao_treasury_token_account else {
+                return err!(EpochError::MissingTokenAccounts);
+            };
+            transfer_from_token_escrow(
+                &self.auction,
+                &auction_token_escrow.to_account_info(),
+                &dao_treasury_token_account.to_account_info(),
+                &token_program.to_account_info(),
+                auction_token_escrow.amount,
+            )?;
+        }
+
+        let epoch = self.auction.epoch.to_le_bytes();
+        let bump = &[self.auction.bump];
+        let seeds: &[&[u8]] = &[AUCTION_SEED.as_bytes(), &epoch, bump];
+        let signer_seeds = &[seeds];
+
+        token::close_account(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token::CloseAccount {
+                    account: auction_token_escrow.to_account_info(),
+                    destination: self.authority.to_account_info(),
+                    authority: self.auction.to_account_info(),
+                },
+            ).with_signer(signer_seeds),
+        )
+    }
+}
@@ -0,0 +1,195 @@
+//! Represents the `CommitBid` instruction, the first step of a sealed-bid auction.
+//! The bidder posts a hashed commitment together with a deposit, which must cover the bid they will reveal.
+//! Depositing more than the intended bid keeps the bid amount private.
+//!
+//! # Instruction Arguments
+//!
+//! - `input_epoch`: The user-input epoch used to seed the auction account.
+//! - `commitment`: keccak256(bid_lamports (u64 LE) || salt || bidder).
+//! - `deposit_lamports`: Lamports escrowed with the commitment. Must be at least the reserve price.
+//! - `allowlist_proof`: Merkle proof of the bidder's wallet against the auction's allowlist root.
+//!   Only needed during the auction's gate window by bidders without enough reputation; otherwise pass an empty proof.
+//!
+//! # Errors
+//!
+//! - `EpochError::InvalidAuctionKind`: If the auction is not a sealed-bid auction.
+//! - `EpochError::AuctionEnded`: If the auction's end slot has been reached.
+//! - `EpochError::BidBelowReserve`: If the deposit is lower than the auction's reserve price.
+//! - `EpochError::BidderNotAllowed`: If the gate window is open and the bidder has neither enough reputation nor a valid allowlist proof.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::utils::emit_cpi_event;
+use crate::{constants::*, EpochError, ReputationChanged, SealedBidCommitted};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(input_epoch: u64)]
+pub struct CommitBid<'info> {
+    #[account(mut, signer)]
+    bidder: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    /// One commitment per bidder per auction
+    #[account(
+        init,
+        seeds = [SEALED_BID_SEED.as_bytes(), &input_epoch.to_le_bytes(), bidder.key().as_ref()],
+        bump,
+        payer = bidder,
+        space = SealedBid::get_size(),
+    )]
+    sealed_bid: Account<'info, SealedBid>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_ESCROW_SEED.as_ref()],
+        bump
+    )]
+    pub auction_escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    escrow_ledger: Account<'info, EscrowLedger>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    stats: Account<'info, Stats>,
+
+    /// Receives the deposit back (less any forfeit) when the sealed bid is resolved
+    #[account(
+        init_if_needed,
+        seeds = [BID_CREDIT_SEED.as_bytes(), bidder.key().as_ref()],
+        bump,
+        payer = bidder,
+        space = BidCredit::get_size(),
+    )]
+    bid_credit: Account<'info, BidCredit>,
+
+    #[account(
+        init_if_needed,
+        seeds = [REPUTATION_SEED.as_bytes(), bidder.key().as_ref()],
+        bump,
+        payer = bidder,
+        space = Reputation::get_size(),
+    )]
+    reputation: Account<'info, Reputation>,
+
+    /// Reputation decay and streak parameters
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    /// Points awarded for each action
+    #[account(
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump = points_table.bump,
+    )]
+    points_table: Account<'info, PointsTable>,
+
+    /// Top contributors by reputation, updated with the new reputation
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump = leaderboard.load()?.bump,
+    )]
+    leaderboard: AccountLoader<'info, Leaderboard>,
+
+    system_program: Program<'info, System>,
+}
+
+impl CommitBid<'_> {
+    pub fn handler(
+        &mut self,
+        input_epoch: u64,
+        commitment: [u8; 32],
+        deposit_lamports: u64,
+        allowlist_proof: Vec<[u8; 32]>,
+        bumps: &CommitBidBumps,
+    ) -> Result<()> {
+        self.validate_commit(input_epoch, deposit_lamports, &allowlist_proof)?;
+        self.transfer_deposit_to_escrow(deposit_lamports)?;
+
+        let bidder = self.bidder.key();
+        self.sealed_bid.commit(input_epoch, bidder, commitment, deposit_lamports, bumps.sealed_bid);
+        self.auction.record_sealed_bid_committed()?;
+        self.bid_credit.init_if_needed(bidder, bumps.bid_credit);
+        if self.bid_credit.record_bid() {
+            self.stats.record_new_bidder()?;
+        }
+        // The bid size is hidden until reveal, so only the base bid points apply
+        let points = self.points_table.bid_points(input_epoch, 0)?;
+        self.reputation.init_if_needed(bidder, bumps.reputation);
+        let points = self.reputation.increment_with_streak(
+            ReputationAction::Bid,
+            points,
+            bidder,
+            &self.config.reputation_params,
+            Clock::get()?.epoch,
+        )?;
+        self.leaderboard.load_mut()?.update(bidder, self.reputation.reputation);
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            &SealedBidCommitted {
+                epoch: input_epoch,
+                bidder,
+                deposit_lamports,
+            },
+        )?;
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            &ReputationChanged {
+                contributor: bidder,
+                points,
+                reputation: self.reputation.reputation,
+            },
+        )
+    }
+
+    fn validate_commit(&self, input_epoch: u64, deposit_lamports: u64, allowlist_proof: &[[u8; 32]]) -> Result<()> {
+        let auction = &self.auction;
+        let clock = Clock::get()?;
+        require!(input_epoch == auction.epoch, EpochError::EpochMismatch);
+        require!(matches!(auction.params.kind, AuctionKind::Sealed { .. }), EpochError::InvalidAuctionKind);
+        require!(auction.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
+        auction.validate_active(clock.slot)?;
+        require!(deposit_lamports >= auction.params.reserve_price_lamports, EpochError::BidBelowReserve);
+        // Checked before this commitment's reputation is awarded
+        let reputation = self.reputation.current_reputation(&self.config.reputation_params, clock.epoch);
+        auction.validate_gate(&self.bidder.key(), reputation, allowlist_proof, clock.slot)
+    }
+
+    fn transfer_deposit_to_escrow(&mut self, deposit_lamports: u64) -> Result<()> {
+        self.escrow_ledger.deposit(deposit_lamports)?;
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.bidder.to_account_info(),
+                    to: self.auction_escrow.to_account_info(),
+                },
+            ),
+            deposit_lamports,
+        )?;
+        Ok(())
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
     utils::{
-        create_asset, get_and_validate_epoch, write_rawimg_and_traits,
-    }, Auction, EpochError, Points, Reputation, AUCTION_SEED, AUTHORITY_SEED, COLLECTION_SEED, NFT_MINT_SEED, REPUTATION_SEED
+        create_asset, emit_cpi_event, get_and_validate_epoch, get_epoch_end_slot, write_rawimg_and_traits,
+    }, Auction, BidHistory, Config, EpochCreated, EpochError, Leaderboard, PointsTable, Reputation, ReputationAction, ReputationChanged, Stats, AUCTION_SEED, AUTHORITY_SEED, BID_HISTORY_SEED, COLLECTION_SEED, CONFIG_SEED, LEADERBOARD_SEED, NFT_MINT_SEED, POINTS_TABLE_SEED, REPUTATION_SEED, STATS_SEED
 };
 use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(input_epoch: u64)]
 pub struct CreateAsset<'info> {
@@ -52,6 +53,16 @@ pub struct CreateAsset<'info> {
     )]
     pub auction: Account<'info, Auction>,
 
+    /// Fixed-size ring buffer of the auction's most recent bids
+    #[account(
+        init,
+        seeds = [BID_HISTORY_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump,
+        payer = payer,
+        space = BidHistory::get_size()
+    )]
+    pub bid_history: AccountLoader<'info, BidHistory>,
+
     #[account(
         init_if_needed,
         seeds = [REPUTATION_SEED.as_bytes(), payer.key().as_ref()],
@@ -61,17 +72,39 @@ pub struct CreateAsset<'info> {
     )]
     pub reputation: Account<'info, Reputation>,
 
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Points awarded for each action
+    #[account(
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump = points_table.bump,
+    )]
+    pub points_table: Account<'info, PointsTable>,
+
+    /// Top contributors by reputation, updated with the new reputation
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump = leaderboard.load()?.bump,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, Stats>,
+
 }
 
 impl<'info> CreateAsset<'info> {
-    pub fn handler(
-        &mut self,
-        authority_bump: u8,
-        asset_bump: u8,
-        reputation_bump: u8,
-        auction_bump: u8,
-        current_epoch: u64,
-    ) -> Result<()> {
+    pub fn handler(&mut self, current_epoch: u64, bumps: &CreateAssetBumps) -> Result<()> {
         let epoch = get_and_validate_epoch(current_epoch)?;
 
         let account_infos = vec![
@@ -86,10 +119,10 @@ impl<'info> CreateAsset<'info> {
         let asset_seeds = &[
             NFT_MINT_SEED.as_bytes(),
             &epoch.to_le_bytes(),
-            &[asset_bump],
+            &[bumps.asset],
         ];
         let asset_signer_seeds: &[&[&[u8]]; 1] = &[&asset_seeds[..]];
-        let authority_seeds = &[AUTHORITY_SEED.as_bytes(), &[authority_bump]];
+        let authority_seeds = &[AUTHORITY_SEED.as_bytes(), &[bumps.authority]];
         let combined_signer_seeds = &[&asset_seeds[..], &authority_seeds[..]];
 
         write_rawimg_and_traits(
@@ -112,6 +145,8 @@ impl<'info> CreateAsset<'info> {
             epoch
         )?;
 
+        let start_slot = Clock::get()?.slot;
+        let end_slot = get_epoch_end_slot(epoch)?;
         let payer = self.payer.key();
         let asset = self.asset.to_account_info();
         let auction: &mut Account<'_, Auction> = &mut self.auction;
@@ -120,16 +155,49 @@ impl<'info> CreateAsset<'info> {
         auction.create(
             current_epoch,
             asset.key(),
-            payer,
-            auction_bump,
+            self.config.auction_params,
+            start_slot,
+            end_slot,
+            bumps.auction,
         );
-
-        reputation.init_if_needed(payer, reputation_bump);
-        reputation.increment_with_validation(Points::INITIATE, payer.key())?;
+        self.bid_history.load_init()?.init(current_epoch, bumps.bid_history);
+        self.stats.record_auction_created()?;
+
+        let points = self.points_table.initiate_points(current_epoch)?;
+        reputation.init_if_needed(payer, bumps.reputation);
+        let points = reputation.increment_with_streak(
+            ReputationAction::Initiate,
+            points,
+            payer.key(),
+            &self.config.reputation_params,
+            Clock::get()?.epoch,
+        )?;
+        self.leaderboard.load_mut()?.update(payer, reputation.reputation);
 
         //TODO Replace anchor init with my own in lieu of refund.
         //maybe track the amount in the auction to prevent some weird abuse where somebody sends lamports to the asset or auction pda
-        self.refund_rent(authority_bump)?;
+        self.refund_rent(bumps.authority)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            &EpochCreated {
+                epoch: current_epoch,
+                asset: self.asset.key(),
+                initiator: payer,
+                start_slot,
+                end_slot,
+            },
+        )?;
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            &ReputationChanged {
+                contributor: payer,
+                points,
+                reputation: self.reputation.reputation,
+            },
+        )?;
 
         Ok(())
     }
@@ -0,0 +1,89 @@
+use crate::utils::{create_group_asset, write_group_metadata, write_grouping};
+use crate::{
+    BadgeConfig, BadgeTier, Config, EpochError, AUTHORITY_SEED, BADGE_CONFIG_SEED, BADGE_GROUP_SEED, CONFIG_SEED
+};
+use anchor_lang::prelude::*;
+use nifty_asset::ID as NiftyAssetID;
+
+/// Creates the "Epochs Reputation" group that soulbound reputation badges are minted into,
+/// and the `BadgeConfig` holding the tier thresholds. Admin only.
+#[derive(Accounts)]
+pub struct CreateBadgeGroup<'info> {
+    #[account(
+        mut,
+        signer,
+        address = config.admin @ EpochError::InvalidAdmin
+    )]
+    pub payer: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [BADGE_CONFIG_SEED.as_bytes()],
+        bump,
+        payer = payer,
+        space = BadgeConfig::get_size(),
+    )]
+    pub badge_config: Account<'info, BadgeConfig>,
+
+    /// CHECK: OSS inits it as an Asset
+    #[account(
+        mut,
+        seeds = [BADGE_GROUP_SEED.as_bytes()],
+        bump,
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: Program Authority: The account that will be used to sign transactions
+    #[account(
+        seeds = [AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: use address constraint
+    #[account(
+        address = NiftyAssetID @ EpochError::InvalidOssProgram
+    )]
+    pub oss_program: UncheckedAccount<'info>,
+}
+
+impl<'info> CreateBadgeGroup<'info> {
+    pub fn handler(&mut self, tiers: Vec<BadgeTier>, badge_config_bump: u8, authority_bump: u8, asset_bump: u8) -> Result<()> {
+        self.badge_config.init(tiers, badge_config_bump)?;
+
+        let account_infos = vec![
+            self.asset.to_account_info(),
+            self.authority.to_account_info(),
+            self.payer.to_account_info(),
+            self.oss_program.to_account_info(),
+            self.system_program.to_account_info(),
+        ];
+
+        let authority_seeds = &[AUTHORITY_SEED.as_bytes(), &[authority_bump]];
+        let asset_seeds = &[BADGE_GROUP_SEED.as_bytes(), &[asset_bump]];
+        let combined_signer_seeds = &[&asset_seeds[..], &authority_seeds[..]];
+
+        let asset = self.asset.key();
+        let payer = self.payer.key();
+        write_group_metadata(
+            asset,
+            payer,
+            "EPOCHSREP",
+            "Soulbound reputation badges for Epochs contributors.",
+            &account_infos,
+            combined_signer_seeds,
+        )?;
+        write_grouping(asset, payer, &account_infos, combined_signer_seeds)?;
+        create_group_asset(asset, self.authority.key(), payer, "Epochs Reputation", &account_infos, combined_signer_seeds)?;
+
+        Ok(())
+    }
+}
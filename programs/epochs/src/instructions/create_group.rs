@@ -1,7 +1,6 @@
-use std::str::FromStr;
-
+use crate::utils::{create_group_asset, write_group_metadata, write_grouping};
 use crate::{
-    EpochError, AUTHORITY, AUTHORITY_SEED, COLLECTION_SEED, CREATOR_1_SHARE, CREATOR_WALLET_1, DAO_TREASURY_SHARE, DAO_TREASURY_WALLET
+    Config, EpochError, AUTHORITY_SEED, COLLECTION_SEED, CONFIG_SEED
 };
 use anchor_lang::{
     prelude::*,
@@ -11,9 +10,9 @@ use anchor_lang::{
     },
 };
 use nifty_asset::{
-    extensions::{CreatorsBuilder, ExtensionBuilder, GroupingBuilder, LinksBuilder, MetadataBuilder, RoyaltiesBuilder},
-    instructions::{AllocateBuilder, CreateBuilder},
-    types::{ExtensionInput, ExtensionType, Standard},
+    extensions::{CreatorsBuilder, ExtensionBuilder, LinksBuilder, RoyaltiesBuilder},
+    instructions::AllocateBuilder,
+    types::{ExtensionInput, ExtensionType},
     ID as NiftyAssetID,
 };
 
@@ -24,10 +23,16 @@ pub struct CreateGroup<'info> {
     #[account(
         mut, 
         signer,
-        address = Pubkey::from_str(AUTHORITY).unwrap() @ EpochError::InvalidTreasury
+        address = config.admin @ EpochError::InvalidAdmin
 )]
     pub payer: SystemAccount<'info>,
 
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
     /// CHECK: OSS inits it as an Asset
     #[account(
         mut,
@@ -66,12 +71,14 @@ impl<'info> CreateGroup<'info> {
         let asset_seeds = &[COLLECTION_SEED.as_bytes(), &[asset_bump]];
         let combined_signer_seeds = &[&asset_seeds[..], &authority_seeds[..]];
 
-        self.write_metadata(&account_infos, combined_signer_seeds)?;
+        let asset = self.asset.key();
+        let payer = self.payer.key();
+        write_group_metadata(asset, payer, "EPOCHS", "One Epoch, every epoch, forever.", &account_infos, combined_signer_seeds)?;
         self.write_creators(&account_infos, combined_signer_seeds)?;
         self.add_royalties(&account_infos, combined_signer_seeds)?;
         self.write_links(&account_infos, combined_signer_seeds)?;
-        self.create_group(&account_infos, combined_signer_seeds)?;
-        self.create_asset(&account_infos, combined_signer_seeds)?;
+        write_grouping(asset, payer, &account_infos, combined_signer_seeds)?;
+        create_group_asset(asset, self.authority.key(), payer, "The Epochs Collection", &account_infos, combined_signer_seeds)?;
 
         Ok(())
     }
@@ -80,9 +87,12 @@ impl<'info> CreateGroup<'info> {
         account_infos: &[AccountInfo],
         signer_seeds: &[&[&[u8]]; 2]
     ) -> Result<()> {
+        // Nifty creator shares are whole percentages, config shares are basis points
         let mut creators = CreatorsBuilder::default();
-        creators.add(&Pubkey::from_str(DAO_TREASURY_WALLET).unwrap(), true, DAO_TREASURY_SHARE);
-        creators.add(&Pubkey::from_str(CREATOR_WALLET_1).unwrap(), true, CREATOR_1_SHARE);
+        creators.add(&self.config.dao_treasury, true, (self.config.treasury_share_bps / 100) as u8);
+        for creator in self.config.creators.iter() {
+            creators.add(&creator.address, true, (creator.share_bps / 100) as u8);
+        }
         
         let creators_data = creators.data();
 
@@ -102,26 +112,6 @@ impl<'info> CreateGroup<'info> {
         Ok(())
     }
 
-    fn create_group(&self, account_infos: &[AccountInfo], signer_seeds: &[&[&[u8]]; 2]) -> Result<()> {
-        let mut group_builder = GroupingBuilder::default();
-        let group_data = group_builder.data();
-
-        let group_ix: Instruction = AllocateBuilder::new()
-            .asset(self.asset.key())
-            .payer(Some(self.payer.key()))
-            .system_program(Some(self.system_program.key()))
-            .extension(ExtensionInput {
-                extension_type: ExtensionType::Grouping,
-                length: group_data.len() as u32,
-                data: Some(group_data),
-            })
-            .instruction();
-
-        invoke_signed(&group_ix, account_infos, signer_seeds)?;
-
-        Ok(())
-    }
-
     fn write_links(&self, account_infos: &[AccountInfo], signer_seeds: &[&[&[u8]]; 2]) -> Result<()> {
         let mut links_builder = LinksBuilder::default();
         links_builder.add(
@@ -152,7 +142,7 @@ impl<'info> CreateGroup<'info> {
 
     fn add_royalties(&self, account_infos: &[AccountInfo], signer_seeds: &[&[&[u8]]; 2]) -> Result<()> {
         let mut royalties_builder = RoyaltiesBuilder::default();
-        royalties_builder.set(500, &mut EmptyBuilder::default(),);
+        royalties_builder.set(self.config.royalty_bps as u64, &mut EmptyBuilder::default(),);
         let royalties_data: Vec<u8> = royalties_builder.data();
 
         let royalties_ix: Instruction = AllocateBuilder::new()
@@ -170,48 +160,4 @@ impl<'info> CreateGroup<'info> {
 
         Ok(())
     }
-
-    fn write_metadata(&self, account_infos: &[AccountInfo], signer_seeds: &[&[&[u8]]; 2]) -> Result<()> {
-        let mut metadata_builder = MetadataBuilder::default();
-        metadata_builder.set(Some("EPOCHS"), Some("One Epoch, every epoch, forever."), Some("https://epochs.wtf/"));
-        
-        let metadata: Vec<u8> = metadata_builder.data();
-
-        let links_ix: Instruction = AllocateBuilder::new()
-            .asset(self.asset.key())
-            .payer(Some(self.payer.key()))
-            .system_program(Some(self.system_program.key()))
-            .extension(ExtensionInput {
-                extension_type: ExtensionType::Metadata,
-                length: metadata.len() as u32,
-                data: Some(metadata),
-            })
-            .instruction();
-
-        invoke_signed(&links_ix, account_infos, signer_seeds)?;
-
-        Ok(())
-    }
-
-    fn create_asset(
-        &self,
-        account_infos: &[AccountInfo],
-        signer_seeds: &[&[&[u8]]; 2]
-    ) -> Result<()> {
-        let create_ix = CreateBuilder::new()
-            .asset(self.asset.key())
-            .authority(self.authority.key(), false)
-            .owner(self.authority.key())
-            .group(None)
-            .payer(Some(self.payer.key()))
-            .system_program(Some(self.system_program.key()))
-            .name("The Epochs Collection".to_string())
-            .standard(Standard::NonFungible)
-            .mutable(true) // ALLOW change to royalties, metadata, etc.
-            .instruction();
-
-        invoke_signed(&create_ix, account_infos, signer_seeds)?;
-
-        Ok(())
-    }
 }
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+
+use crate::{Config, ConfigArgs, EpochError, EscrowLedger, Stats, AUTHORITY, CONFIG_SEED, ESCROW_LEDGER_SEED, STATS_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// Only the bootstrap authority can create the config.
+    /// Admin rights are handed to `args.admin` from then on.
+    #[account(
+        mut,
+        address = Pubkey::from_str(AUTHORITY).unwrap() @ EpochError::InvalidAdmin
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        payer = payer,
+        space = Config::get_size(),
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump,
+        payer = payer,
+        space = EscrowLedger::get_size(),
+    )]
+    pub escrow_ledger: Account<'info, EscrowLedger>,
+
+    #[account(
+        init,
+        seeds = [STATS_SEED.as_bytes()],
+        bump,
+        payer = payer,
+        space = Stats::get_size(),
+    )]
+    pub stats: Account<'info, Stats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeConfig<'_> {
+    pub fn handler(&mut self, args: ConfigArgs, config_bump: u8, escrow_ledger_bump: u8, stats_bump: u8) -> Result<()> {
+        self.escrow_ledger.init(escrow_ledger_bump);
+        self.stats.init(stats_bump);
+        self.config.init(args, config_bump)
+    }
+}
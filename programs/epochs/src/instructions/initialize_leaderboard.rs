@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, EpochError, Leaderboard, CONFIG_SEED, LEADERBOARD_SEED};
+
+/// Creates the `Leaderboard`. Only the config admin can create it, and every instruction that awards
+/// reputation requires it. Existing contributors can be added with `sync_leaderboard`.
+#[derive(Accounts)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        has_one = admin @ EpochError::InvalidAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump,
+        payer = admin,
+        space = Leaderboard::get_size(),
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeLeaderboard<'_> {
+    pub fn handler(&mut self, leaderboard_bump: u8) -> Result<()> {
+        self.leaderboard.load_init()?.init(leaderboard_bump);
+        Ok(())
+    }
+}
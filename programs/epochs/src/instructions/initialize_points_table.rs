@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, EpochError, PointsTable, PointsTableArgs, CONFIG_SEED, POINTS_TABLE_SEED};
+
+/// Creates the `PointsTable`. Only the config admin can create it, and every instruction that awards
+/// reputation requires it.
+#[derive(Accounts)]
+pub struct InitializePointsTable<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        has_one = admin @ EpochError::InvalidAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump,
+        payer = admin,
+        space = PointsTable::get_size(),
+    )]
+    pub points_table: Account<'info, PointsTable>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializePointsTable<'_> {
+    pub fn handler(&mut self, args: PointsTableArgs, points_table_bump: u8) -> Result<()> {
+        self.points_table.init(args, points_table_bump)
+    }
+}
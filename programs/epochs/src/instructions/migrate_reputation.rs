@@ -0,0 +1,65 @@
+//! Represents the `MigrateReputation` instruction, which grows a `Reputation` account created with an older layout
+//! to the current `Reputation::get_size()`. The new fields are zero-filled, which reads as "no streak",
+//! "decay not yet started" and an empty per-action breakdown. Old accounts cannot be deserialized until they are
+//! migrated. Permissionless; the payer covers the additional rent. Does nothing if the account is already the current size.
+//!
+//! # Instruction Arguments
+//!
+//! - `contributor`: The owner of the reputation account, used to derive its address.
+//!
+//! # Errors
+//!
+//! - `EpochError::InvalidReputationAccount`: If the account is not owned by this program or is not a `Reputation` account.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::{constants::*, EpochError};
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(contributor: Pubkey)]
+pub struct MigrateReputation<'info> {
+    #[account(mut, signer)]
+    payer: SystemAccount<'info>,
+
+    /// CHECK: Deserializing would fail on the old layout, so the owner and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [REPUTATION_SEED.as_bytes(), contributor.as_ref()],
+        bump,
+    )]
+    reputation: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl MigrateReputation<'_> {
+    pub fn handler(&mut self) -> Result<()> {
+        let reputation = self.reputation.to_account_info();
+        require_keys_eq!(*reputation.owner, crate::ID, EpochError::InvalidReputationAccount);
+        require!(
+            reputation.try_borrow_data()?.starts_with(&Reputation::DISCRIMINATOR),
+            EpochError::InvalidReputationAccount
+        );
+
+        let new_size = Reputation::get_size();
+        if reputation.data_len() >= new_size {
+            return Ok(());
+        }
+
+        let rent_due = Rent::get()?.minimum_balance(new_size).saturating_sub(reputation.lamports());
+        if rent_due > 0 {
+            let cpi_accounts = Transfer {
+                from: self.payer.to_account_info(),
+                to: reputation.clone(),
+            };
+            let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+            transfer(cpi_ctx, rent_due)?;
+        }
+
+        reputation.realloc(new_size, true)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+//! Represents the `MintBadge` instruction, which mints a contributor's first soulbound reputation badge.
+//! The badge is a non-transferable Nifty asset in the "Epochs Reputation" group, named after its tier.
+//! Contributors can mint any tier their current (decayed) reputation qualifies for and later move up with `upgrade_badge`.
+//!
+//! # Instruction Arguments
+//!
+//! - `tier`: Index into `BadgeConfig::tiers`. Clients should pick the highest tier the contributor qualifies for.
+//!
+//! # Errors
+//!
+//! - `EpochError::InvalidBadgeTier`: If the tier does not exist.
+//! - `EpochError::InsufficientReputation`: If the contributor's reputation is below the tier's threshold.
+
+use anchor_lang::prelude::*;
+use nifty_asset::ID as NiftyAssetID;
+
+use crate::{constants::*, BadgeMinted, EpochError};
+use crate::state::*;
+use crate::utils::{create_badge, BadgeAccounts, emit_cpi_event};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct MintBadge<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        seeds = [REPUTATION_SEED.as_bytes(), contributor.key().as_ref()],
+        bump = reputation.bump,
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    /// Reputation decay parameters
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [BADGE_CONFIG_SEED.as_bytes()],
+        bump = badge_config.bump,
+    )]
+    pub badge_config: Account<'info, BadgeConfig>,
+
+    /// One badge per contributor; `upgrade_badge` replaces its asset
+    #[account(
+        init,
+        seeds = [BADGE_SEED.as_bytes(), contributor.key().as_ref()],
+        bump,
+        payer = contributor,
+        space = Badge::get_size(),
+    )]
+    pub badge: Account<'info, Badge>,
+
+    /// CHECK: New badge asset (will be init by OSS Program via CPI - address is derived from the contributor and tier)
+    #[account(
+        mut,
+        seeds = [BADGE_ASSET_SEED.as_bytes(), contributor.key().as_ref(), &[tier]],
+        bump,
+    )]
+    pub badge_asset: UncheckedAccount<'info>,
+
+    /// CHECK: Badge Group Asset
+    #[account(
+        mut,
+        seeds = [BADGE_GROUP_SEED.as_bytes()],
+        bump,
+    )]
+    pub badge_group: UncheckedAccount<'info>,
+
+    /// CHECK: Program Authority: The account that will be used to sign transactions
+    #[account(
+        seeds = [AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: use address constraint
+    #[account(
+        address = NiftyAssetID @ EpochError::InvalidOssProgram
+    )]
+    pub oss_program: UncheckedAccount<'info>,
+}
+
+impl MintBadge<'_> {
+    pub fn handler(
+        &mut self,
+        tier: u8,
+        badge_bump: u8,
+        badge_asset_bump: u8,
+        authority_bump: u8,
+        event_authority_bump: u8,
+    ) -> Result<()> {
+        let contributor = self.contributor.key();
+        let reputation = self.reputation.current_reputation(&self.config.reputation_params, Clock::get()?.epoch);
+        let tier_name = self.badge_config.validate_tier(tier, reputation)?.name.clone();
+        self.badge_config.record_minted(tier);
+
+        let account_infos = vec![
+            self.badge_asset.to_account_info(),
+            self.contributor.to_account_info(),
+            self.badge_group.to_account_info(),
+            self.authority.to_account_info(),
+            self.oss_program.to_account_info(),
+            self.system_program.to_account_info(),
+        ];
+        let asset_seeds = &[
+            BADGE_ASSET_SEED.as_bytes(),
+            contributor.as_ref(),
+            &[tier],
+            &[badge_asset_bump],
+        ];
+        let authority_seeds = &[AUTHORITY_SEED.as_bytes(), &[authority_bump]];
+        let combined_signer_seeds = &[&asset_seeds[..], &authority_seeds[..]];
+
+        let badge = BadgeAccounts {
+            asset: self.badge_asset.key(),
+            payer: contributor,
+            authority: self.authority.key(),
+            owner: contributor,
+            group: self.badge_group.key(),
+        };
+        create_badge(&badge, &tier_name, &account_infos, combined_signer_seeds)?;
+        self.badge.init(contributor, self.badge_asset.key(), tier, badge_bump);
+
+        emit_cpi_event(
+            &self.event_authority,
+            event_authority_bump,
+            &BadgeMinted {
+                contributor,
+                asset: self.badge_asset.key(),
+                tier,
+                previous_asset: None,
+            },
+        )
+    }
+}
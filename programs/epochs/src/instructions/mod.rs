@@ -2,8 +2,58 @@ pub mod auction_bid;
 pub mod create_asset;
 pub mod create_group;
 pub mod auction_claim;
+pub mod initialize_config;
+pub mod update_config;
+pub mod settle_auction;
+pub mod settle_unsold_auction;
+pub mod withdraw_vault_asset;
+pub mod withdraw_credit;
+pub mod withdraw_token_credit;
+pub mod release_bid_deposit;
+pub mod verify_escrow;
+pub mod sweep_excess;
+pub mod commit_bid;
+pub mod reveal_bid;
+pub mod resolve_sealed_bid;
+pub mod buy_now;
+pub mod close_auction;
+pub mod transfer_win;
+pub mod migrate_reputation;
+pub mod initialize_points_table;
+pub mod update_points_table;
+pub mod create_badge_group;
+pub mod update_badge_tiers;
+pub mod mint_badge;
+pub mod upgrade_badge;
+pub mod initialize_leaderboard;
+pub mod sync_leaderboard;
 
 pub use auction_bid::*;
 pub use create_asset::*;
 pub use create_group::*;
 pub use auction_claim::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use settle_auction::*;
+pub use settle_unsold_auction::*;
+pub use withdraw_vault_asset::*;
+pub use withdraw_credit::*;
+pub use withdraw_token_credit::*;
+pub use release_bid_deposit::*;
+pub use verify_escrow::*;
+pub use sweep_excess::*;
+pub use commit_bid::*;
+pub use reveal_bid::*;
+pub use resolve_sealed_bid::*;
+pub use buy_now::*;
+pub use close_auction::*;
+pub use transfer_win::*;
+pub use migrate_reputation::*;
+pub use initialize_points_table::*;
+pub use update_points_table::*;
+pub use create_badge_group::*;
+pub use update_badge_tiers::*;
+pub use mint_badge::*;
+pub use upgrade_badge::*;
+pub use initialize_leaderboard::*;
+pub use sync_leaderboard::*;
@@ -0,0 +1,106 @@
+//! Represents the `ReleaseBidDeposit` instruction, which returns a bidder's deposit in an English auction
+//! once it no longer backs the leading bid. Lamports are credited to the bidder's `BidCredit` (they stay in
+//! the auction escrow until withdrawn), tokens to the bidder's `TokenCredit` (they move to the mint's token credit vault until withdrawn).
+//! For the winner only the part of their proxy maximum that was not needed is returned, after the auction is claimed or settled.
+//! Permissionless, so anybody can clean up deposits before the auction is closed. The deposit's rent goes back to the bidder.
+//!
+//! # Errors
+//!
+//! - `EpochError::BidStillLeading`: If the deposit belongs to the high bidder and the auction has not been claimed or settled.
+//! - `EpochError::MissingTokenAccounts`: If a token auction is missing any of its token accounts.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::utils::transfer_from_token_escrow;
+use crate::{constants::*, EpochError};
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(input_epoch: u64)]
+pub struct ReleaseBidDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    /// CHECK: Owner of the deposit, receives its rent
+    #[account(mut)]
+    bidder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BID_DEPOSIT_SEED.as_bytes(), &input_epoch.to_le_bytes(), bidder.key().as_ref()],
+        bump = bid_deposit.bump,
+        has_one = bidder,
+        close = bidder,
+    )]
+    bid_deposit: Account<'info, BidDeposit>,
+
+    /// Created with the deposit, so it always exists
+    #[account(
+        mut,
+        seeds = [BID_CREDIT_SEED.as_bytes(), bidder.key().as_ref()],
+        bump = bid_credit.bump,
+    )]
+    bid_credit: Account<'info, BidCredit>,
+
+    // Token auctions only (`auction.params.bid_mint` is set), omit for lamport auctions
+
+    #[account(
+        mut,
+        seeds = [AUCTION_TOKEN_ESCROW_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump,
+    )]
+    auction_token_escrow: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Created with the deposit, so it always exists
+    #[account(
+        mut,
+        seeds = [TOKEN_CREDIT_SEED.as_bytes(), auction.params.bid_mint.unwrap_or_default().as_ref(), bidder.key().as_ref()],
+        bump = token_credit.bump,
+    )]
+    token_credit: Option<Box<Account<'info, TokenCredit>>>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_CREDIT_VAULT_SEED.as_bytes(), auction.params.bid_mint.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    token_credit_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    token_program: Option<Program<'info, Token>>,
+}
+
+impl ReleaseBidDeposit<'_> {
+    pub fn handler(&mut self, input_epoch: u64) -> Result<()> {
+        require!(input_epoch == self.auction.epoch, EpochError::EpochMismatch);
+
+        let amount = self.bid_deposit.releasable(&self.auction)?;
+        self.bid_deposit.amount = 0;
+        self.auction.record_deposit_released()?;
+
+        if self.auction.params.bid_mint.is_none() {
+            return self.bid_credit.credit(amount);
+        }
+
+        let (Some(auction_token_escrow), Some(token_credit), Some(token_credit_vault), Some(token_program)) = (
+            &self.auction_token_escrow,
+            &mut self.token_credit,
+            &self.token_credit_vault,
+            &self.token_program,
+        ) else {
+            return err!(EpochError::MissingTokenAccounts);
+        };
+        token_credit.credit(amount)?;
+        transfer_from_token_escrow(
+            &self.auction,
+            &auction_token_escrow.to_account_info(),
+            &token_credit_vault.to_account_info(),
+            &token_program.to_account_info(),
+            amount,
+        )
+    }
+}
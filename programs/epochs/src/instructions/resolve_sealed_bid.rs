@@ -0,0 +1,149 @@
+//! Represents the `ResolveSealedBid` instruction, which anybody can call once a sealed-bid auction's
+//! reveal window has closed. It returns the bidder's deposit to their `BidCredit`:
+//!
+//! - Unrevealed: `forfeit_bps` of the deposit goes to the DAO treasury, the rest is credited.
+//! - Revealed, winner: the deposit less the clearing price is credited (the clearing price is paid out at claim).
+//! - Revealed, not the winner: the full deposit is credited.
+//!
+//! The `SealedBid` account is closed and its rent returned to the bidder.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{constants::*, EpochError};
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(input_epoch: u64)]
+pub struct ResolveSealedBid<'info> {
+    /// CHECK: Receives the sealed bid rent, validated against the sealed bid
+    #[account(
+        mut,
+        address = sealed_bid.bidder @ EpochError::InvalidBidder,
+    )]
+    bidder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [SEALED_BID_SEED.as_bytes(), &input_epoch.to_le_bytes(), sealed_bid.bidder.as_ref()],
+        bump = sealed_bid.bump,
+    )]
+    sealed_bid: Account<'info, SealedBid>,
+
+    #[account(
+        mut,
+        seeds = [BID_CREDIT_SEED.as_bytes(), sealed_bid.bidder.as_ref()],
+        bump = bid_credit.bump,
+    )]
+    bid_credit: Account<'info, BidCredit>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_ESCROW_SEED.as_ref()],
+        bump
+    )]
+    pub auction_escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    escrow_ledger: Account<'info, EscrowLedger>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    stats: Account<'info, Stats>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.dao_treasury @ EpochError::InvalidTreasury
+    )]
+    dao_treasury: SystemAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl ResolveSealedBid<'_> {
+    pub fn handler(&mut self, input_epoch: u64, escrow_bump: u8) -> Result<()> {
+        require!(input_epoch == self.auction.epoch, EpochError::EpochMismatch);
+        self.auction.validate_ended(Clock::get()?.slot)?;
+
+        let (forfeit, refund) = self.split_deposit()?;
+        self.forfeit_to_treasury(forfeit, escrow_bump)?;
+        self.bid_credit.credit(refund)?;
+        self.auction.record_sealed_bid_resolved()?;
+        Ok(())
+    }
+
+    /// Returns (lamports forfeited to the treasury, lamports credited back to the bidder)
+    fn split_deposit(&self) -> Result<(u64, u64)> {
+        let sealed_bid = &self.sealed_bid;
+        let auction = &self.auction;
+        let deposit = sealed_bid.deposit_lamports;
+
+        if !sealed_bid.revealed {
+            let forfeit_bps = match auction.params.kind {
+                AuctionKind::Sealed { forfeit_bps, .. } => forfeit_bps,
+                _ => return err!(EpochError::InvalidAuctionKind),
+            };
+            let forfeit = (deposit as u128)
+                .checked_mul(forfeit_bps as u128)
+                .ok_or(EpochError::Overflow)?
+                / BPS_DENOMINATOR as u128;
+            let forfeit = forfeit as u64;
+            return Ok((forfeit, deposit - forfeit));
+        }
+
+        if sealed_bid.bidder == auction.high_bidder {
+            let refund = deposit
+                .checked_sub(auction.clearing_price())
+                .ok_or(EpochError::Underflow)?;
+            return Ok((0, refund));
+        }
+
+        Ok((0, deposit))
+    }
+
+    fn forfeit_to_treasury(&mut self, forfeit: u64, escrow_bump: u8) -> Result<()> {
+        if forfeit == 0 {
+            return Ok(());
+        }
+        self.escrow_ledger.withdraw(forfeit)?;
+        self.stats.record_treasury_lamports(forfeit)?;
+
+        let bump = &[escrow_bump];
+        let seeds: &[&[u8]] = &[AUCTION_ESCROW_SEED.as_ref(), bump];
+        let signer_seeds = &[seeds];
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.auction_escrow.to_account_info(),
+                    to: self.dao_treasury.to_account_info(),
+                },
+            ).with_signer(signer_seeds),
+            forfeit,
+        )?;
+        Ok(())
+    }
+}
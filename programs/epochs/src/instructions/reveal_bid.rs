@@ -0,0 +1,45 @@
+//! Represents the `RevealBid` instruction, the second step of a sealed-bid auction.
+//! During the reveal window (after `end_slot`), the bidder reveals their bid and salt.
+//! The highest revealed bid becomes the auction's high bid.
+//!
+//! # Errors
+//!
+//! - `EpochError::RevealNotOpen` / `EpochError::RevealClosed`: If called outside the reveal window.
+//! - `EpochError::InvalidReveal`: If the bid and salt do not match the commitment.
+//! - `EpochError::BidExceedsDeposit`: If the bid is larger than the deposit.
+//! - `EpochError::BidBelowReserve`: If the bid is lower than the auction's reserve price.
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, EpochError};
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(input_epoch: u64)]
+pub struct RevealBid<'info> {
+    bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [SEALED_BID_SEED.as_bytes(), &input_epoch.to_le_bytes(), bidder.key().as_ref()],
+        bump = sealed_bid.bump,
+        has_one = bidder,
+    )]
+    sealed_bid: Account<'info, SealedBid>,
+}
+
+impl RevealBid<'_> {
+    pub fn handler(&mut self, input_epoch: u64, bid_lamports: u64, salt: [u8; 32]) -> Result<()> {
+        require!(input_epoch == self.auction.epoch, EpochError::EpochMismatch);
+        self.sealed_bid.reveal(bid_lamports, &salt)?;
+        self.auction.reveal(self.bidder.key(), bid_lamports, Clock::get()?.slot)?;
+        Ok(())
+    }
+}
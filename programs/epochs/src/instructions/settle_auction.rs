@@ -0,0 +1,342 @@
+//! Represents the `SettleAuction` instruction, which allows anybody to settle an auction that has ended.
+//! The asset is sent to the high bidder (or the recipient they named), the escrowed bid is distributed to the DAO treasury and creators,
+//! the winner receives the `PointsTable` win points and the settler receives the settle points.
+//! Any unused part of the winner's proxy maximum can then be returned with `release_bid_deposit`.
+//!
+//! Once the winner has left the auction unclaimed for `claim_grace_epochs` epochs, the settler receives the force-settle points instead.
+//! If `forfeit_unclaimed_to_dao` is set, the auction can only be settled after that grace period, and the asset then goes to the
+//! DAO treasury without win points.
+//!
+//! The winner should use `auction_claim` instead of settling their own auction.
+//!
+//! # Errors
+//!
+//! - `EpochError::InvalidSettler`: If the settler is the winner of the auction.
+//! - `EpochError::InvalidWinner`: If the winner account does not match the auction's high bidder.
+//! - `EpochError::AuctionNotEnded`: If the auction's end slot has not been reached.
+//! - `EpochError::GracePeriodNotOver`: If unclaimed assets are forfeited and the claim grace period has not passed yet.
+//! - `EpochError::AuctionAlreadyClaimed`: If the auction has already been claimed or settled.
+//! - `EpochError::NoBids`: If nobody bid on the auction (use `settle_unsold_auction`).
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use nifty_asset::ID as NiftyAssetID;
+
+use crate::utils::{
+    distribute_nft, emit_settlement_events, get_grace_end_slot, pay_rent, Distribution, SettlementFunds,
+};
+use crate::{constants::*, EpochError, ReputationChanged};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(settle_epoch: u64)]
+pub struct SettleAuction<'info> {
+    /// Anybody but the winner can settle
+    #[account(
+        mut,
+        signer,
+        constraint = settler.key() != auction.high_bidder @ EpochError::InvalidSettler,
+    )]
+    settler: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &settle_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_ESCROW_SEED.as_ref()],
+        bump
+    )]
+    pub auction_escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    escrow_ledger: Account<'info, EscrowLedger>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    stats: Account<'info, Stats>,
+
+    /// CHECK: Recipient of the asset, validated against the auction's high bidder
+    #[account(
+        address = auction.high_bidder @ EpochError::InvalidWinner,
+    )]
+    winner: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the asset, validated against the auction's asset recipient
+    #[account(
+        address = auction.asset_recipient() @ EpochError::InvalidRecipient,
+    )]
+    recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [REPUTATION_SEED.as_bytes(), auction.high_bidder.as_ref()],
+        bump = winner_reputation.bump,
+    )]
+    winner_reputation: Account<'info, Reputation>,
+
+    /// PDA that will store the reputation for the settler
+    /// Need to use `init_if_needed` bc we are not sure if the settler has a reputation account
+    #[account(
+        init_if_needed,
+        seeds = [REPUTATION_SEED.as_bytes(), settler.key().as_ref()],
+        bump,
+        payer = settler,
+        space = Reputation::get_size(),
+    )]
+    settler_reputation: Account<'info, Reputation>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    /// Points awarded for each action
+    #[account(
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump = points_table.bump,
+    )]
+    points_table: Account<'info, PointsTable>,
+
+    /// Top contributors by reputation, updated with the new reputation
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump = leaderboard.load()?.bump,
+    )]
+    leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        address = config.dao_treasury @ EpochError::InvalidTreasury
+    )]
+    dao_treasury: SystemAccount<'info>,
+
+    /// Credited with the referral fee of the winning bid. Required when `auction.referrer` is set
+    /// Created by the referred bid
+    #[account(
+        mut,
+        seeds = [BID_CREDIT_SEED.as_bytes(), auction.referrer.as_ref()],
+        bump = referrer_credit.bump,
+    )]
+    referrer_credit: Option<Account<'info, BidCredit>>,
+
+    // Creator wallets are passed as remaining accounts, in the same order as `config.creators`
+
+    /// CHECK: use bump seeds and validate on auction
+    #[account(
+        mut,
+        seeds = [NFT_MINT_SEED.as_bytes(), &settle_epoch.to_le_bytes()],
+        bump,
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: use bump seeds and validate on auction
+    #[account(
+        mut,
+        seeds = [AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    pub authority: AccountInfo<'info>,
+
+    /// CHECK: use address constraint
+    #[account(
+        address = NiftyAssetID @ EpochError::InvalidOssProgram
+    )]
+    pub oss_program: UncheckedAccount<'info>,
+
+    /// CHECK: Group Asset
+    #[account(
+        mut,
+        seeds = [COLLECTION_SEED.as_bytes()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+
+    // Token auctions only (`auction.params.bid_mint` is set), omit for lamport auctions.
+    // Creator token accounts are then passed as remaining accounts instead of creator wallets
+
+    #[account(
+        mut,
+        seeds = [AUCTION_TOKEN_ESCROW_SEED.as_bytes(), &settle_epoch.to_le_bytes()],
+        bump,
+    )]
+    auction_token_escrow: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Validated against `config.dao_treasury` when distributing
+    #[account(mut)]
+    dao_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Token equivalent of `referrer_credit`. Required when `auction.referrer` is set
+    #[account(
+        mut,
+        seeds = [TOKEN_CREDIT_SEED.as_bytes(), auction.params.bid_mint.unwrap_or_default().as_ref(), auction.referrer.as_ref()],
+        bump = referrer_token_credit.bump,
+    )]
+    referrer_token_credit: Option<Box<Account<'info, TokenCredit>>>,
+
+    /// Receives the referral fee for `referrer_token_credit`. Required when `auction.referrer` is set
+    #[account(
+        mut,
+        seeds = [TOKEN_CREDIT_VAULT_SEED.as_bytes(), auction.params.bid_mint.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    token_credit_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    token_program: Option<Program<'info, Token>>,
+}
+
+impl<'info> SettleAuction<'info> {
+    pub fn handler(
+        &mut self,
+        settle_epoch: u64,
+        auction_escrow_bump: u8,
+        authority_bump: u8,
+        settler_reputation_bump: u8,
+        event_authority_bump: u8,
+        creator_wallets: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let grace_period_over = self.validate_settlement(settle_epoch)?;
+        pay_rent(
+            &self.settler.to_account_info(),
+            &self.authority.to_account_info(),
+            &self.asset.to_account_info(),
+            &self.auction.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+        let distribution = self.distribute_funds(auction_escrow_bump, creator_wallets)?;
+        self.stats.record_sale(
+            self.auction.epoch,
+            self.auction.clearing_price(),
+            &distribution,
+            self.auction.params.bid_mint.is_none(),
+        )?;
+        self.distribute_nft(authority_bump)?;
+        let (win_points, settle_points) = self.update_auction_and_reputation(settler_reputation_bump, grace_period_over)?;
+        self.emit_events(event_authority_bump, distribution, win_points, settle_points)?;
+        Ok(())
+    }
+
+    /// Returns whether the claim grace period has passed
+    fn validate_settlement(&self, settle_epoch: u64) -> Result<bool> {
+        require!(settle_epoch == self.auction.epoch, EpochError::EpochMismatch);
+        let slot = Clock::get()?.slot;
+        self.auction.validate_ended(slot)?;
+        let grace_end_slot = get_grace_end_slot(self.auction.settlement_slot()?, self.auction.params.claim_grace_epochs)?;
+        let grace_period_over = slot >= grace_end_slot;
+        // Otherwise settling would hand the asset to a winner who is about to forfeit it
+        if self.auction.params.forfeit_unclaimed_to_dao {
+            require!(grace_period_over, EpochError::GracePeriodNotOver);
+        }
+        require!(self.auction.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
+        require!(self.auction.has_bids(), EpochError::NoBids);
+        Ok(grace_period_over)
+    }
+
+    fn distribute_funds(&mut self, escrow_bump: u8, creator_wallets: &[AccountInfo<'info>]) -> Result<Distribution> {
+        SettlementFunds {
+            config: &self.config,
+            auction: &self.auction,
+            escrow_ledger: &mut self.escrow_ledger,
+            auction_escrow: self.auction_escrow.as_ref(),
+            dao_treasury: self.dao_treasury.as_ref(),
+            referrer_credit: self.referrer_credit.as_mut(),
+            system_program: self.system_program.as_ref(),
+            auction_token_escrow: self.auction_token_escrow.as_deref(),
+            dao_treasury_token_account: self.dao_treasury_token_account.as_deref(),
+            referrer_token_credit: self.referrer_token_credit.as_deref_mut(),
+            token_credit_vault: self.token_credit_vault.as_deref(),
+            token_program: self.token_program.as_ref().map(|token_program| token_program.as_ref()),
+        }
+        .distribute(escrow_bump, creator_wallets)
+    }
+
+    /// Sends the asset to the DAO treasury when unclaimed assets are forfeited, otherwise to the winner's recipient
+    fn distribute_nft(&self, authority_bump: u8) -> Result<()> {
+        let recipient = if self.auction.params.forfeit_unclaimed_to_dao {
+            self.dao_treasury.to_account_info()
+        } else {
+            self.recipient.to_account_info()
+        };
+        distribute_nft(&self.asset, &self.authority, &recipient, &self.group, &self.oss_program, authority_bump)
+    }
+
+    /// Returns the points awarded to the winner and to the settler
+    fn update_auction_and_reputation(&mut self, settler_reputation_bump: u8, grace_period_over: bool) -> Result<(u64, u64)> {
+        let settler = self.settler.key();
+        let epoch = Clock::get()?.epoch;
+        let win_points = self.points_table.win_points(self.auction.epoch)?;
+        let settle_points = if grace_period_over {
+            self.points_table.force_settle_points(self.auction.epoch)?
+        } else {
+            self.points_table.settle_points(self.auction.epoch)?
+        };
+        self.auction.claim()?;
+        if !self.auction.params.forfeit_unclaimed_to_dao {
+            self.winner_reputation.increment_with_validation(
+                ReputationAction::Win,
+                win_points,
+                self.winner.key(),
+                &self.config.reputation_params,
+                epoch,
+            )?;
+        }
+        self.settler_reputation.init_if_needed(settler, settler_reputation_bump);
+        self.settler_reputation.increment_with_validation(
+            ReputationAction::Settle,
+            settle_points,
+            settler,
+            &self.config.reputation_params,
+            epoch,
+        )?;
+
+        let mut leaderboard = self.leaderboard.load_mut()?;
+        if !self.auction.params.forfeit_unclaimed_to_dao {
+            leaderboard.update(self.winner.key(), self.winner_reputation.reputation);
+        }
+        leaderboard.update(settler, self.settler_reputation.reputation);
+        Ok((win_points, settle_points))
+    }
+
+    fn emit_events(&self, event_authority_bump: u8, distribution: Distribution, win_points: u64, settle_points: u64) -> Result<()> {
+        let forfeited = self.auction.params.forfeit_unclaimed_to_dao;
+        let mut reputation_changes = Vec::with_capacity(2);
+        if !forfeited {
+            reputation_changes.push(ReputationChanged {
+                contributor: self.auction.high_bidder,
+                points: win_points,
+                reputation: self.winner_reputation.reputation,
+            });
+        }
+        reputation_changes.push(ReputationChanged {
+            contributor: self.settler.key(),
+            points: settle_points,
+            reputation: self.settler_reputation.reputation,
+        });
+
+        emit_settlement_events(
+            &self.event_authority,
+            event_authority_bump,
+            &self.auction,
+            if forfeited { self.dao_treasury.key() } else { self.recipient.key() },
+            distribution,
+            &reputation_changes,
+        )
+    }
+}
@@ -0,0 +1,195 @@
+//! Represents the `SettleUnsoldAuction` instruction, which allows anybody to close out an auction
+//! that ended without any bids. The asset is handled according to the auction's `NoBidPolicy`:
+//! sent to the DAO treasury, burned, or moved to the program vault.
+//! The settler receives the `PointsTable` settle points.
+//!
+//! # Errors
+//!
+//! - `EpochError::AuctionNotEnded`: If the auction's end slot has not been reached.
+//! - `EpochError::AuctionAlreadyClaimed`: If the auction has already been claimed or settled.
+//! - `EpochError::AuctionHasBids`: If the auction has a high bidder (use `auction_claim` or `settle_auction`).
+
+use anchor_lang::prelude::*;
+
+use nifty_asset::ID as NiftyAssetID;
+
+use crate::utils::{burn_asset, emit_cpi_event, transfer_asset};
+use crate::{constants::*, AuctionUnsold, EpochError, ReputationChanged};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(settle_epoch: u64)]
+pub struct SettleUnsoldAuction<'info> {
+    #[account(mut, signer)]
+    settler: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &settle_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    #[account(
+        init_if_needed,
+        seeds = [REPUTATION_SEED.as_bytes(), settler.key().as_ref()],
+        bump,
+        payer = settler,
+        space = Reputation::get_size(),
+    )]
+    settler_reputation: Account<'info, Reputation>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    /// Points awarded for each action
+    #[account(
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump = points_table.bump,
+    )]
+    points_table: Account<'info, PointsTable>,
+
+    /// Top contributors by reputation, updated with the new reputation
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump = leaderboard.load()?.bump,
+    )]
+    leaderboard: AccountLoader<'info, Leaderboard>,
+
+    /// CHECK: Recipient under `NoBidPolicy::Treasury`
+    #[account(
+        address = config.dao_treasury @ EpochError::InvalidTreasury
+    )]
+    dao_treasury: UncheckedAccount<'info>,
+
+    /// CHECK: Program vault, recipient under `NoBidPolicy::Vault`
+    #[account(
+        seeds = [VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    vault: UncheckedAccount<'info>,
+
+    /// CHECK: use bump seeds and validate on auction
+    #[account(
+        mut,
+        seeds = [NFT_MINT_SEED.as_bytes(), &settle_epoch.to_le_bytes()],
+        bump,
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: use bump seeds and validate on auction
+    #[account(
+        mut,
+        seeds = [AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    pub authority: AccountInfo<'info>,
+
+    /// CHECK: use address constraint
+    #[account(
+        address = NiftyAssetID @ EpochError::InvalidOssProgram
+    )]
+    pub oss_program: UncheckedAccount<'info>,
+
+    /// CHECK: Group Asset
+    #[account(
+        mut,
+        seeds = [COLLECTION_SEED.as_bytes()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl SettleUnsoldAuction<'_> {
+    pub fn handler(&mut self, settle_epoch: u64, authority_bump: u8, settler_reputation_bump: u8, event_authority_bump: u8) -> Result<()> {
+        require!(settle_epoch == self.auction.epoch, EpochError::EpochMismatch);
+        self.auction.validate_ended(Clock::get()?.slot)?;
+        self.auction.mark_unsold()?;
+
+        self.apply_no_bid_policy(authority_bump)?;
+
+        let settler = self.settler.key();
+        let points = self.points_table.settle_points(self.auction.epoch)?;
+        self.settler_reputation.init_if_needed(settler, settler_reputation_bump);
+        let epoch = Clock::get()?.epoch;
+        let points = self.settler_reputation.increment_with_validation(
+            ReputationAction::Settle,
+            points,
+            settler,
+            &self.config.reputation_params,
+            epoch,
+        )?;
+        self.leaderboard.load_mut()?.update(settler, self.settler_reputation.reputation);
+
+        emit_cpi_event(
+            &self.event_authority,
+            event_authority_bump,
+            &AuctionUnsold {
+                epoch: self.auction.epoch,
+                no_bid_policy: self.auction.params.no_bid_policy,
+                settler,
+            },
+        )?;
+        emit_cpi_event(
+            &self.event_authority,
+            event_authority_bump,
+            &ReputationChanged {
+                contributor: settler,
+                points,
+                reputation: self.settler_reputation.reputation,
+            },
+        )
+    }
+
+    fn apply_no_bid_policy(&self, authority_bump: u8) -> Result<()> {
+        let bump = &[authority_bump];
+        let seeds: &[&[u8]] = &[AUTHORITY_SEED.as_ref(), bump];
+        let signer_seeds = &[seeds];
+
+        let recipient = match self.auction.params.no_bid_policy {
+            NoBidPolicy::Treasury => self.dao_treasury.to_account_info(),
+            NoBidPolicy::Vault => self.vault.to_account_info(),
+            NoBidPolicy::Burn => {
+                // Asset rent goes back to the authority, which paid for it
+                let account_infos = vec![
+                    self.asset.to_account_info(),
+                    self.authority.to_account_info(),
+                    self.group.to_account_info(),
+                    self.oss_program.to_account_info(),
+                ];
+                return burn_asset(
+                    self.asset.key(),
+                    self.authority.key(),
+                    self.authority.key(),
+                    self.group.key(),
+                    &account_infos,
+                    signer_seeds,
+                );
+            }
+        };
+
+        let account_infos = vec![
+            self.asset.to_account_info(),
+            self.authority.to_account_info(),
+            recipient.clone(),
+            self.group.to_account_info(),
+            self.oss_program.to_account_info(),
+        ];
+
+        transfer_asset(
+            self.asset.key(),
+            self.authority.key(),
+            recipient.key(),
+            self.group.key(),
+            &account_infos,
+            signer_seeds,
+        )
+    }
+}
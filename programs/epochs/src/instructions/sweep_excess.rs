@@ -0,0 +1,68 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+
+use crate::{Config, EpochError, EscrowLedger, Stats, AUCTION_ESCROW_SEED, CONFIG_SEED, ESCROW_LEDGER_SEED, STATS_SEED};
+
+/// Permissionless: sends any escrow lamports beyond what is owed (and the escrow's rent-exempt minimum)
+/// to the DAO treasury.
+#[derive(Accounts)]
+pub struct SweepExcess<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_ESCROW_SEED.as_ref()],
+        bump
+    )]
+    pub auction_escrow: SystemAccount<'info>,
+
+    #[account(
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    pub escrow_ledger: Account<'info, EscrowLedger>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, Stats>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.dao_treasury @ EpochError::InvalidTreasury
+    )]
+    pub dao_treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SweepExcess<'_> {
+    pub fn handler(&mut self, escrow_bump: u8) -> Result<()> {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        let surplus = self.escrow_ledger.surplus(self.auction_escrow.lamports(), rent_exempt_minimum)?;
+        require!(surplus > 0, EpochError::NoEscrowSurplus);
+        self.stats.record_treasury_lamports(surplus)?;
+
+        let bump = &[escrow_bump];
+        let seeds: &[&[u8]] = &[AUCTION_ESCROW_SEED.as_ref(), bump];
+        let signer_seeds = &[seeds];
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.auction_escrow.to_account_info(),
+                    to: self.dao_treasury.to_account_info(),
+                },
+            ).with_signer(signer_seeds),
+            surplus,
+        )?;
+        Ok(())
+    }
+}
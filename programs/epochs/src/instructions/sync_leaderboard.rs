@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, Leaderboard, Reputation, CONFIG_SEED, LEADERBOARD_SEED, REPUTATION_SEED};
+
+/// Permissionless: pushes a contributor's current (decayed) reputation into the leaderboard.
+/// Used to add contributors whose reputation predates the leaderboard, or to refresh an entry after decay.
+#[derive(Accounts)]
+pub struct SyncLeaderboard<'info> {
+    #[account(
+        seeds = [REPUTATION_SEED.as_bytes(), reputation.contributor.as_ref()],
+        bump = reputation.bump,
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    /// Reputation decay parameters
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED.as_bytes()],
+        bump = leaderboard.load()?.bump,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+}
+
+impl SyncLeaderboard<'_> {
+    pub fn handler(&self) -> Result<()> {
+        let reputation = self.reputation.current_reputation(&self.config.reputation_params, Clock::get()?.epoch);
+        self.leaderboard.load_mut()?.update(self.reputation.contributor, reputation);
+        Ok(())
+    }
+}
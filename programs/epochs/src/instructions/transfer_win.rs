@@ -0,0 +1,200 @@
+//! Represents the `TransferWin` instruction, which lets the winner of an ended, unsettled auction hand the
+//! right to claim it to another wallet (e.g., an OTC sale). The new winner can then `claim` the auction.
+//!
+//! The high bid moves from the seller's `BidDeposit` to the new winner's, so the new winner only takes over
+//! the price actually owed. Any unused part of the seller's proxy maximum stays in their deposit, which they
+//! can then release. An optional `price_lamports` is paid by the new winner into the auction escrow in the
+//! same transaction and credited to the seller's `BidCredit`.
+//! A recipient named by the seller is cleared. English lamport auctions only.
+//!
+//! # Instruction Arguments
+//!
+//! - `input_epoch`: The user-input epoch used to seed the auction account.
+//! - `price_lamports`: Lamports the new winner pays the seller (0 for a gift, in which case the new winner does not need to sign).
+//!
+//! # Errors
+//!
+//! - `EpochError::InvalidWinner`: If the signer is not the auction's winner.
+//! - `EpochError::InvalidNewWinner`: If the new winner is the seller, or does not sign while paying a price.
+//! - `EpochError::AuctionNotEnded`: If the auction has not ended.
+//! - `EpochError::AuctionAlreadyClaimed`: If the auction has already been claimed or settled.
+//! - `EpochError::InvalidAuctionKind`: If the auction is not an English auction.
+//! - `EpochError::TokenAuctionNotSupported`: If the auction is a token auction.
+
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+
+use crate::utils::emit_cpi_event;
+use crate::{constants::*, EpochError, WinTransferred};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(input_epoch: u64)]
+pub struct TransferWin<'info> {
+    #[account(
+        mut,
+        constraint = winner.key() == auction.high_bidder @ EpochError::InvalidWinner,
+    )]
+    winner: Signer<'info>,
+
+    /// Must sign when paying a price
+    #[account(
+        mut,
+        constraint = new_winner.key() != winner.key() @ EpochError::InvalidNewWinner,
+    )]
+    new_winner: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_ESCROW_SEED.as_ref()],
+        bump
+    )]
+    pub auction_escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    escrow_ledger: Account<'info, EscrowLedger>,
+
+    /// Protocol-wide totals
+    #[account(
+        mut,
+        seeds = [STATS_SEED.as_bytes()],
+        bump = stats.bump,
+    )]
+    stats: Account<'info, Stats>,
+
+    /// The seller's credit, receives the price
+    #[account(
+        mut,
+        seeds = [BID_CREDIT_SEED.as_bytes(), winner.key().as_ref()],
+        bump = winner_credit.bump,
+    )]
+    winner_credit: Account<'info, BidCredit>,
+
+    /// The seller's deposit, which backs the winning bid
+    #[account(
+        mut,
+        seeds = [BID_DEPOSIT_SEED.as_bytes(), &input_epoch.to_le_bytes(), winner.key().as_ref()],
+        bump = winner_deposit.bump,
+    )]
+    winner_deposit: Account<'info, BidDeposit>,
+
+    /// Takes over the high bid from the seller's deposit
+    #[account(
+        init_if_needed,
+        seeds = [BID_DEPOSIT_SEED.as_bytes(), &input_epoch.to_le_bytes(), new_winner.key().as_ref()],
+        bump,
+        payer = winner,
+        space = BidDeposit::get_size(),
+    )]
+    new_winner_deposit: Account<'info, BidDeposit>,
+
+    /// `claim` and `release_bid_deposit` need the new winner's credit and reputation accounts, so the seller creates them if needed
+    #[account(
+        init_if_needed,
+        seeds = [BID_CREDIT_SEED.as_bytes(), new_winner.key().as_ref()],
+        bump,
+        payer = winner,
+        space = BidCredit::get_size(),
+    )]
+    new_winner_credit: Account<'info, BidCredit>,
+
+    #[account(
+        init_if_needed,
+        seeds = [REPUTATION_SEED.as_bytes(), new_winner.key().as_ref()],
+        bump,
+        payer = winner,
+        space = Reputation::get_size(),
+    )]
+    new_winner_reputation: Account<'info, Reputation>,
+
+    system_program: Program<'info, System>,
+}
+
+impl TransferWin<'_> {
+    pub fn handler(
+        &mut self,
+        input_epoch: u64,
+        price_lamports: u64,
+        new_winner_credit_bump: u8,
+        new_winner_deposit_bump: u8,
+        new_winner_reputation_bump: u8,
+        event_authority_bump: u8,
+    ) -> Result<()> {
+        require!(input_epoch == self.auction.epoch, EpochError::EpochMismatch);
+        require!(self.auction.params.bid_mint.is_none(), EpochError::TokenAuctionNotSupported);
+        require!(price_lamports == 0 || self.new_winner.is_signer, EpochError::InvalidNewWinner);
+
+        let new_winner = self.new_winner.key();
+        self.new_winner_credit.init_if_needed(new_winner, new_winner_credit_bump);
+        if self.new_winner_credit.record_bid() {
+            self.stats.record_new_bidder()?;
+        }
+        self.new_winner_reputation.init_if_needed(new_winner, new_winner_reputation_bump);
+
+        self.auction.transfer_win(self.winner.key(), new_winner, Clock::get()?.slot)?;
+        self.move_high_bid(input_epoch, new_winner, new_winner_deposit_bump)?;
+
+        if price_lamports > 0 {
+            self.pay_seller(price_lamports)?;
+        }
+
+        emit_cpi_event(
+            &self.event_authority,
+            event_authority_bump,
+            &WinTransferred {
+                epoch: self.auction.epoch,
+                from: self.winner.key(),
+                to: new_winner,
+                price_lamports,
+            },
+        )
+    }
+
+    /// The escrowed high bid now backs the new winner's claim. The rest of the seller's deposit is no longer leading
+    fn move_high_bid(&mut self, input_epoch: u64, new_winner: Pubkey, new_winner_deposit_bump: u8) -> Result<()> {
+        let high_bid = self.auction.high_bid_lamports;
+        self.winner_deposit.amount = self
+            .winner_deposit
+            .amount
+            .checked_sub(high_bid)
+            .ok_or(EpochError::Underflow)?;
+
+        if self.new_winner_deposit.init_if_needed(input_epoch, new_winner, new_winner_deposit_bump) {
+            self.auction.record_deposit_opened()?;
+        }
+        self.new_winner_deposit.amount = self
+            .new_winner_deposit
+            .amount
+            .checked_add(high_bid)
+            .ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    /// The price goes into escrow and is owed to the seller through their `BidCredit`
+    fn pay_seller(&mut self, price_lamports: u64) -> Result<()> {
+        self.escrow_ledger.deposit(price_lamports)?;
+        self.winner_credit.credit(price_lamports)?;
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.new_winner.to_account_info(),
+                    to: self.auction_escrow.to_account_info(),
+                },
+            ),
+            price_lamports,
+        )
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{BadgeConfig, BadgeTier, Config, EpochError, BADGE_CONFIG_SEED, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct UpdateBadgeTiers<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        has_one = admin @ EpochError::InvalidAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [BADGE_CONFIG_SEED.as_bytes()],
+        bump = badge_config.bump,
+    )]
+    pub badge_config: Account<'info, BadgeConfig>,
+}
+
+impl UpdateBadgeTiers<'_> {
+    pub fn handler(&mut self, tiers: Vec<BadgeTier>) -> Result<()> {
+        self.badge_config.update(tiers)
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, ConfigArgs, EpochError, CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        has_one = admin @ EpochError::InvalidAdmin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl UpdateConfig<'_> {
+    pub fn handler(&mut self, args: ConfigArgs) -> Result<()> {
+        self.config.update(args)
+    }
+}
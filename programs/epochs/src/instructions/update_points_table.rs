@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, EpochError, PointsTable, PointsTableArgs, CONFIG_SEED, POINTS_TABLE_SEED};
+
+#[derive(Accounts)]
+pub struct UpdatePointsTable<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        has_one = admin @ EpochError::InvalidAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [POINTS_TABLE_SEED.as_bytes()],
+        bump = points_table.bump,
+    )]
+    pub points_table: Account<'info, PointsTable>,
+}
+
+impl UpdatePointsTable<'_> {
+    pub fn handler(&mut self, args: PointsTableArgs) -> Result<()> {
+        self.points_table.update(args)
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{EscrowLedger, AUCTION_ESCROW_SEED, ESCROW_LEDGER_SEED};
+
+/// Permissionless solvency check: fails with `EscrowInsolvent` if the escrow holds less than it owes.
+#[derive(Accounts)]
+pub struct VerifyEscrow<'info> {
+    #[account(
+        seeds = [AUCTION_ESCROW_SEED.as_ref()],
+        bump
+    )]
+    pub auction_escrow: SystemAccount<'info>,
+
+    #[account(
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    pub escrow_ledger: Account<'info, EscrowLedger>,
+}
+
+impl VerifyEscrow<'_> {
+    pub fn handler(&self) -> Result<()> {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        let surplus = self.escrow_ledger.surplus(self.auction_escrow.lamports(), rent_exempt_minimum)?;
+        msg!(
+            "Escrow holds {} lamports, owes {}, surplus {}",
+            self.auction_escrow.lamports(),
+            self.escrow_ledger.total_outstanding_lamports,
+            surplus
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+
+use crate::{BidCredit, EpochError, EscrowLedger, AUCTION_ESCROW_SEED, BID_CREDIT_SEED, ESCROW_LEDGER_SEED};
+
+/// Withdraws a bidder's entire `BidCredit` balance from the auction escrow back to their wallet.
+#[derive(Accounts)]
+pub struct WithdrawCredit<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BID_CREDIT_SEED.as_bytes(), bidder.key().as_ref()],
+        bump = bid_credit.bump,
+        has_one = bidder,
+    )]
+    pub bid_credit: Account<'info, BidCredit>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_ESCROW_SEED.as_ref()],
+        bump
+    )]
+    pub auction_escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_LEDGER_SEED.as_bytes()],
+        bump = escrow_ledger.bump,
+    )]
+    pub escrow_ledger: Account<'info, EscrowLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl WithdrawCredit<'_> {
+    pub fn handler(&mut self, escrow_bump: u8) -> Result<()> {
+        let lamports = self.bid_credit.lamports;
+        require!(lamports > 0, EpochError::NoCredit);
+        self.bid_credit.debit(lamports)?;
+        self.escrow_ledger.withdraw(lamports)?;
+
+        let bump = &[escrow_bump];
+        let seeds: &[&[u8]] = &[AUCTION_ESCROW_SEED.as_ref(), bump];
+        let signer_seeds = &[seeds];
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.auction_escrow.to_account_info(),
+                    to: self.bidder.to_account_info(),
+                },
+            ).with_signer(signer_seeds),
+            lamports,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::{EpochError, TokenCredit, AUTHORITY_SEED, TOKEN_CREDIT_SEED, TOKEN_CREDIT_VAULT_SEED};
+
+/// Withdraws a wallet's entire `TokenCredit` balance from the mint's token credit vault to one of its token accounts.
+#[derive(Accounts)]
+pub struct WithdrawTokenCredit<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_CREDIT_SEED.as_bytes(), token_credit.mint.as_ref(), owner.key().as_ref()],
+        bump = token_credit.bump,
+        has_one = owner,
+    )]
+    pub token_credit: Account<'info, TokenCredit>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_CREDIT_VAULT_SEED.as_bytes(), token_credit.mint.as_ref()],
+        bump,
+    )]
+    pub token_credit_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_credit.mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Program Authority, owner of the token credit vault
+    #[account(
+        seeds = [AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl WithdrawTokenCredit<'_> {
+    pub fn handler(&mut self, authority_bump: u8) -> Result<()> {
+        let amount = self.token_credit.amount;
+        require!(amount > 0, EpochError::NoCredit);
+        self.token_credit.amount = 0;
+
+        let bump = &[authority_bump];
+        let seeds: &[&[u8]] = &[AUTHORITY_SEED.as_ref(), bump];
+        let signer_seeds = &[seeds];
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.token_credit_vault.to_account_info(),
+                    to: self.owner_token_account.to_account_info(),
+                    authority: self.authority.to_account_info(),
+                },
+            ).with_signer(signer_seeds),
+            amount,
+        )
+    }
+}
@@ -0,0 +1,97 @@
+//! Represents the `WithdrawVaultAsset` instruction, which lets the config admin move the asset of an auction
+//! that was held in the program vault under `NoBidPolicy::Vault` to another wallet (e.g., to sell it later).
+//!
+//! # Errors
+//!
+//! - `EpochError::InvalidAdmin`: If the signer is not the config admin.
+//! - `EpochError::AssetNotInVault`: If the auction was not resolved as unsold under `NoBidPolicy::Vault`.
+
+use anchor_lang::prelude::*;
+
+use nifty_asset::ID as NiftyAssetID;
+
+use crate::utils::transfer_asset;
+use crate::{constants::*, EpochError};
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(input_epoch: u64)]
+pub struct WithdrawVaultAsset<'info> {
+    admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        has_one = admin @ EpochError::InvalidAdmin,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        seeds = [AUCTION_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    auction: Account<'info, Auction>,
+
+    /// CHECK: Program vault, current owner of the asset
+    #[account(
+        seeds = [VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    vault: UncheckedAccount<'info>,
+
+    /// CHECK: Any wallet chosen by the admin
+    recipient: UncheckedAccount<'info>,
+
+    /// CHECK: use bump seeds and validate on auction
+    #[account(
+        mut,
+        seeds = [NFT_MINT_SEED.as_bytes(), &input_epoch.to_le_bytes()],
+        bump,
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: use address constraint
+    #[account(
+        address = NiftyAssetID @ EpochError::InvalidOssProgram
+    )]
+    pub oss_program: UncheckedAccount<'info>,
+
+    /// CHECK: Group Asset
+    #[account(
+        mut,
+        seeds = [COLLECTION_SEED.as_bytes()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+}
+
+impl WithdrawVaultAsset<'_> {
+    pub fn handler(&self, input_epoch: u64, vault_bump: u8) -> Result<()> {
+        require!(input_epoch == self.auction.epoch, EpochError::EpochMismatch);
+        require!(
+            self.auction.state == AuctionState::Unsold && self.auction.params.no_bid_policy == NoBidPolicy::Vault,
+            EpochError::AssetNotInVault
+        );
+
+        let bump = &[vault_bump];
+        let seeds: &[&[u8]] = &[VAULT_SEED.as_ref(), bump];
+        let signer_seeds = &[seeds];
+
+        let account_infos = vec![
+            self.asset.to_account_info(),
+            self.vault.to_account_info(),
+            self.recipient.to_account_info(),
+            self.group.to_account_info(),
+            self.oss_program.to_account_info(),
+        ];
+
+        transfer_asset(
+            self.asset.key(),
+            self.vault.key(),
+            self.recipient.key(),
+            self.group.key(),
+            &account_infos,
+            signer_seeds,
+        )
+    }
+}
@@ -5,12 +5,14 @@ pub mod constants;
 pub mod instructions;
 pub mod state;
 pub mod error;
+pub mod events;
 
 pub use utils::traits::*;
 pub use constants::*;
 pub use instructions::*;
 pub use state::*;
 pub use error::*;
+pub use events::*;
 
 declare_id!("epoZLPTE49aXQ5WLem3W3Jr2thfVgZWFJwkRJZGBsfS");
 
@@ -19,20 +21,120 @@ pub mod epochs {
 
     use super::*;
 
+    pub fn initialize_config(ctx: Context<InitializeConfig>, args: ConfigArgs) -> Result<()> {
+        ctx.accounts.handler(args, ctx.bumps.config, ctx.bumps.escrow_ledger, ctx.bumps.stats)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, args: ConfigArgs) -> Result<()> {
+        ctx.accounts.handler(args)
+    }
+
+    pub fn initialize_points_table(ctx: Context<InitializePointsTable>, args: PointsTableArgs) -> Result<()> {
+        ctx.accounts.handler(args, ctx.bumps.points_table)
+    }
+
+    pub fn update_points_table(ctx: Context<UpdatePointsTable>, args: PointsTableArgs) -> Result<()> {
+        ctx.accounts.handler(args)
+    }
+
+    pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.leaderboard)
+    }
+
+    pub fn sync_leaderboard(ctx: Context<SyncLeaderboard>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
     pub fn create_group(ctx: Context<CreateGroup>) -> Result<()> {
         ctx.accounts.handler(ctx.bumps.authority, ctx.bumps.asset)
     }
 
+    pub fn create_badge_group(ctx: Context<CreateBadgeGroup>, tiers: Vec<BadgeTier>) -> Result<()> {
+        ctx.accounts.handler(tiers, ctx.bumps.badge_config, ctx.bumps.authority, ctx.bumps.asset)
+    }
+
+    pub fn update_badge_tiers(ctx: Context<UpdateBadgeTiers>, tiers: Vec<BadgeTier>) -> Result<()> {
+        ctx.accounts.handler(tiers)
+    }
+
+    pub fn mint_badge(ctx: Context<MintBadge>, tier: u8) -> Result<()> {
+        ctx.accounts.handler(tier, ctx.bumps.badge, ctx.bumps.badge_asset, ctx.bumps.authority, ctx.bumps.event_authority)
+    }
+
+    pub fn upgrade_badge(ctx: Context<UpgradeBadge>, tier: u8) -> Result<()> {
+        ctx.accounts.handler(tier, ctx.bumps.badge_asset, ctx.bumps.authority, ctx.bumps.event_authority)
+    }
+
     pub fn create_epoch(ctx: Context<CreateAsset>, input_epoch: u64) -> Result<()> {
-        ctx.accounts.handler(ctx.bumps.authority, ctx.bumps.asset, ctx.bumps.reputation, ctx.bumps.auction, input_epoch)
+        ctx.accounts.handler(input_epoch, &ctx.bumps)
+    }
+
+    pub fn auction_bid(ctx: Context<AuctionBid>, input_epoch: u64, bid_amount: u64, use_credit: bool, max_amount: Option<u64>, allowlist_proof: Vec<[u8; 32]>, recipient: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.handler(input_epoch, bid_amount, use_credit, max_amount, allowlist_proof, recipient, &ctx.bumps)
+    }
+
+    pub fn commit_bid(ctx: Context<CommitBid>, input_epoch: u64, commitment: [u8; 32], deposit_lamports: u64, allowlist_proof: Vec<[u8; 32]>) -> Result<()> {
+        ctx.accounts.handler(input_epoch, commitment, deposit_lamports, allowlist_proof, &ctx.bumps)
+    }
+
+    pub fn reveal_bid(ctx: Context<RevealBid>, input_epoch: u64, bid_lamports: u64, salt: [u8; 32]) -> Result<()> {
+        ctx.accounts.handler(input_epoch, bid_lamports, salt)
+    }
+
+    pub fn resolve_sealed_bid(ctx: Context<ResolveSealedBid>, input_epoch: u64) -> Result<()> {
+        ctx.accounts.handler(input_epoch, ctx.bumps.auction_escrow)
+    }
+
+    pub fn buy_now<'info>(ctx: Context<'_, '_, '_, 'info, BuyNow<'info>>, input_epoch: u64, max_price: u64, allowlist_proof: Vec<[u8; 32]>, recipient: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.handler(input_epoch, max_price, allowlist_proof, recipient, &ctx.bumps, ctx.remaining_accounts)
+    }
+
+    pub fn close_auction(ctx: Context<CloseAuction>, input_epoch: u64) -> Result<()> {
+        ctx.accounts.handler(input_epoch, ctx.bumps.event_authority)
+    }
+
+    pub fn withdraw_credit(ctx: Context<WithdrawCredit>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.auction_escrow)
+    }
+
+    pub fn withdraw_token_credit(ctx: Context<WithdrawTokenCredit>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.authority)
+    }
+
+    pub fn release_bid_deposit(ctx: Context<ReleaseBidDeposit>, input_epoch: u64) -> Result<()> {
+        ctx.accounts.handler(input_epoch)
+    }
+
+    pub fn verify_escrow(ctx: Context<VerifyEscrow>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn sweep_excess(ctx: Context<SweepExcess>) -> Result<()> {
+        ctx.accounts.handler(ctx.bumps.auction_escrow)
+    }
+
+    pub fn auction_claim<'info>(ctx: Context<'_, '_, '_, 'info, AuctionClaim<'info>>, input_epoch: u64, recipient: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.handler(input_epoch, recipient, ctx.bumps.auction_escrow, ctx.bumps.authority, ctx.bumps.event_authority, ctx.remaining_accounts)
+    }
+
+    pub fn transfer_win(ctx: Context<TransferWin>, input_epoch: u64, price_lamports: u64) -> Result<()> {
+        ctx.accounts.handler(input_epoch, price_lamports, ctx.bumps.new_winner_credit, ctx.bumps.new_winner_deposit, ctx.bumps.new_winner_reputation, ctx.bumps.event_authority)
+    }
+
+    pub fn settle_auction<'info>(ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>, input_epoch: u64) -> Result<()> {
+        ctx.accounts.handler(input_epoch, ctx.bumps.auction_escrow, ctx.bumps.authority, ctx.bumps.settler_reputation, ctx.bumps.event_authority, ctx.remaining_accounts)
+    }
+
+    pub fn settle_unsold_auction(ctx: Context<SettleUnsoldAuction>, input_epoch: u64) -> Result<()> {
+        ctx.accounts.handler(input_epoch, ctx.bumps.authority, ctx.bumps.settler_reputation, ctx.bumps.event_authority)
     }
 
-    pub fn auction_bid(ctx: Context<AuctionBid>, input_epoch: u64, bid_amount: u64) -> Result<()> {
-        ctx.accounts.handler(input_epoch, bid_amount, ctx.bumps.auction_escrow, ctx.bumps.reputation)
+    pub fn withdraw_vault_asset(ctx: Context<WithdrawVaultAsset>, input_epoch: u64) -> Result<()> {
+        ctx.accounts.handler(input_epoch, ctx.bumps.vault)
     }
 
-    pub fn auction_claim(ctx: Context<AuctionClaim>, input_epoch: u64) -> Result<()> {
-        ctx.accounts.handler(input_epoch, ctx.bumps.auction_escrow, ctx.bumps.authority)
+    pub fn migrate_reputation(ctx: Context<MigrateReputation>, _contributor: Pubkey) -> Result<()> {
+        ctx.accounts.handler()
     }
 
 }
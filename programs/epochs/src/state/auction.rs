@@ -3,7 +3,7 @@ use anchor_lang::{
     solana_program:: stake_history::Epoch
 };
 
-use crate::EpochError;
+use crate::{utils::{allowlist_leaf, verify_merkle_proof}, EpochError, BPS_DENOMINATOR};
 
 #[account]
 pub struct Auction {
@@ -13,18 +13,184 @@ pub struct Auction {
     pub high_bidder: Pubkey,
     pub high_bid_lamports: u64,
     pub bump: u8,
+    /// Snapshot of `Config::auction_params` taken when the auction was created
+    pub params: AuctionParams,
+    /// First slot at which bids are no longer accepted.
+    /// Starts at the first slot of the next epoch and can be pushed back by late bids.
+    pub end_slot: u64,
+    /// Second highest revealed bid (sealed auctions only), used for second-price settlement
+    pub second_bid_lamports: u64,
+    /// Slot the auction was created in, where a Dutch auction's price starts decaying
+    pub start_slot: u64,
+    /// Hidden maximum of the high bidder, fully escrowed (English auctions only).
+    /// The visible `high_bid_lamports` is only raised up to this when somebody bids against it.
+    pub proxy_max_lamports: u64,
+    /// Frontend that referred the leading bid (`Pubkey::default()` for none).
+    /// Paid `Config::referral_bps` of the price at settlement.
+    pub referrer: Pubkey,
+    /// Wallet the asset is delivered to instead of the winner (`Pubkey::default()` for the winner).
+    /// Set with the leading bid or named by the winner at claim.
+    pub recipient: Pubkey,
+    /// `BidDeposit` accounts not released yet (English auctions only). The auction cannot be closed until this is 0
+    pub open_deposits: u64,
+    /// `SealedBid` commitments not resolved yet (sealed auctions only). The auction cannot be closed until this is 0
+    pub unresolved_sealed_bids: u64,
 }
 
+/// Result of a bid, telling the instruction which lamports to move
+pub struct BidOutcome {
+    /// Whether the bidder now holds the auction
+    pub is_high_bidder: bool,
+    /// High bidder before this bid
+    pub previous_high_bidder: Pubkey,
+    /// Lamports of `previous_high_bidder`'s deposit that no longer back the leading bid
+    pub refund_lamports: u64,
+    /// Lamports the bidder must put in escrow
+    pub escrow_lamports: u64,
+}
+
+/// Bidding rules for an auction. Configured globally and copied onto each auction at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AuctionParams {
+    /// No bid below this amount is accepted
+    pub reserve_price_lamports: u64,
+    /// Every bid must beat the current high bid by at least this many lamports (must be non-zero)
+    pub min_increment_lamports: u64,
+    /// Every bid must beat the current high bid by at least this share of it (0 to disable)
+    pub min_increment_bps: u16,
+    /// A bid landing within this many slots of the end moves the end to `bid slot + window` (0 to disable)
+    pub extension_window_slots: u64,
+    pub no_bid_policy: NoBidPolicy,
+    pub kind: AuctionKind,
+    /// SPL token that bids are placed in, or `None` for lamports (English auctions only).
+    /// For token auctions every amount in these params and on the auction is in the token's base units.
+    pub bid_mint: Option<Pubkey>,
+    /// Epochs after the auction ends (counting from the epoch it ended in) before anybody can force-settle it
+    pub claim_grace_epochs: u64,
+    /// Whether a force-settled asset goes to the DAO treasury instead of the winner
+    pub forfeit_unclaimed_to_dao: bool,
+    /// During the first `gate_window_slots` slots of the auction only gated bidders may bid (0 to disable)
+    pub gate_window_slots: u64,
+    /// Bidders with at least this much reputation pass the gate (0 to disable this rule)
+    pub gate_min_reputation: u64,
+    /// Merkle root of allowlisted wallets that pass the gate (all zeros to disable this rule)
+    pub gate_allowlist_root: [u8; 32],
+}
+
+impl AuctionParams {
+    pub fn get_size() -> usize {
+        8 +     // reserve_price_lamports
+        8 +     // min_increment_lamports
+        2 +     // min_increment_bps
+        8 +     // extension_window_slots
+        1 +     // no_bid_policy
+        AuctionKind::get_size() + // kind
+        1 + 32 + // bid_mint
+        8 +     // claim_grace_epochs
+        1 +     // forfeit_unclaimed_to_dao
+        8 +     // gate_window_slots
+        8 +     // gate_min_reputation
+        32      // gate_allowlist_root
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.min_increment_bps as u64 <= BPS_DENOMINATOR, EpochError::InvalidBasisPoints);
+        // Every bid must be strictly higher than the last, or the same amount could be re-bid for free
+        require!(self.min_increment_lamports > 0, EpochError::InvalidAuctionParams);
+        require!(
+            self.bid_mint.is_none() || self.kind == AuctionKind::English,
+            EpochError::InvalidAuctionParams
+        );
+        // A gate window needs at least one way through it
+        require!(
+            self.gate_window_slots == 0 || self.gate_min_reputation > 0 || self.has_allowlist(),
+            EpochError::InvalidAuctionParams
+        );
+        // Winners must get at least one epoch to claim before they can lose the asset
+        require!(
+            !self.forfeit_unclaimed_to_dao || self.claim_grace_epochs > 0,
+            EpochError::InvalidAuctionParams
+        );
+        match self.kind {
+            AuctionKind::English => {}
+            AuctionKind::Sealed { reveal_window_slots, forfeit_bps, .. } => {
+                require!(reveal_window_slots > 0, EpochError::InvalidAuctionParams);
+                require!(forfeit_bps as u64 <= BPS_DENOMINATOR, EpochError::InvalidBasisPoints);
+            }
+            AuctionKind::Dutch { start_price, floor_price, .. } => {
+                require!(start_price >= floor_price, EpochError::InvalidAuctionParams);
+            }
+        }
+        Ok(())
+    }
+
+    fn has_allowlist(&self) -> bool {
+        self.gate_allowlist_root != [0; 32]
+    }
+}
+
+/// How bids are placed and the winner is priced
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Default, PartialEq, Eq)]
+pub enum AuctionKind {
+    /// Open ascending auction using `auction_bid`
+    #[default]
+    English,
+    /// Bidders `commit_bid` a hash plus a deposit before `end_slot`, then `reveal_bid` during the reveal window
+    Sealed {
+        /// Number of slots after `end_slot` during which bids can be revealed
+        reveal_window_slots: u64,
+        /// Share of an unrevealed deposit sent to the DAO treasury
+        forfeit_bps: u16,
+        pricing: SealedBidPricing,
+    },
+    /// Descending price auction. The first `buy_now` settles immediately at the current price
+    Dutch {
+        /// Price in lamports at `start_slot`
+        start_price: u64,
+        /// The price never decays below this
+        floor_price: u64,
+        /// Lamports taken off the price every slot
+        decay_per_slot: u64,
+    },
+}
+
+impl AuctionKind {
+    pub fn get_size() -> usize {
+        1 +     // variant
+        8 +     // start_price (largest variant is Dutch)
+        8 +     // floor_price
+        8       // decay_per_slot
+    }
+}
+
+/// What the winner of a sealed auction pays
 #[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub enum SealedBidPricing {
+    /// The winner pays their own bid
+    FirstPrice,
+    /// The winner pays the second highest revealed bid (or the reserve if there is none)
+    SecondPrice,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Default, PartialEq, Eq)]
 pub enum AuctionState {
+    #[default]
     UnClaimed,
     Claimed,
+    /// Auction ended without any bids and the asset was handled per `NoBidPolicy`
+    Unsold,
 }
 
-impl Default for AuctionState {
-    fn default() -> Self {
-        Self::UnClaimed
-    }
+/// What happens to the asset of an auction that ends without any bids
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Default, PartialEq, Eq)]
+pub enum NoBidPolicy {
+    /// Send the asset to the DAO treasury
+    #[default]
+    Treasury,
+    /// Burn the asset
+    Burn,
+    /// Hold the asset in the program vault for a later sale (see `withdraw_vault_asset`)
+    Vault,
 }
 
 impl Auction {
@@ -35,35 +201,308 @@ impl Auction {
         2 +     // state
         32 +    // high_bidder
         8 +     // high_bid
-        1 // bump
+        1 +     // bump
+        AuctionParams::get_size() + // params
+        8 +     // end_slot
+        8 +     // second_bid_lamports
+        8 +     // start_slot
+        8 +     // proxy_max_lamports
+        32 +    // referrer
+        32 +    // recipient
+        8 +     // open_deposits
+        8       // unresolved_sealed_bids
     }
 
-    pub fn create(&mut self, epoch: Epoch, mint: Pubkey, initiator: Pubkey, bump: u8) {
+    pub fn create(&mut self, epoch: Epoch, mint: Pubkey, params: AuctionParams, start_slot: u64, end_slot: u64, bump: u8) {
         self.epoch = epoch;
         self.mint = mint;
         self.state = AuctionState::UnClaimed;
-        self.high_bidder = initiator;
+        // Nobody holds the auction until the first bid
+        self.high_bidder = Pubkey::default();
         self.high_bid_lamports = 0;
         self.bump = bump;
+        self.params = params;
+        self.end_slot = end_slot;
+        self.second_bid_lamports = 0;
+        self.start_slot = start_slot;
+        self.proxy_max_lamports = 0;
+        self.referrer = Pubkey::default();
+        self.recipient = Pubkey::default();
+        self.open_deposits = 0;
+        self.unresolved_sealed_bids = 0;
+    }
+
+    pub fn validate_active(&self, slot: u64) -> Result<()> {
+        require!(slot < self.end_slot, EpochError::AuctionEnded);
+        Ok(())
+    }
+
+    /// First slot at which the auction can be claimed or settled (after the reveal window for sealed auctions)
+    pub fn settlement_slot(&self) -> Result<u64> {
+        match self.params.kind {
+            AuctionKind::Sealed { reveal_window_slots, .. } => self
+                .end_slot
+                .checked_add(reveal_window_slots)
+                .ok_or_else(|| EpochError::Overflow.into()),
+            _ => Ok(self.end_slot),
+        }
+    }
+
+    pub fn validate_ended(&self, slot: u64) -> Result<()> {
+        require!(slot >= self.settlement_slot()?, EpochError::AuctionNotEnded);
+        Ok(())
+    }
+
+    pub fn validate_reveal_open(&self, slot: u64) -> Result<()> {
+        require!(matches!(self.params.kind, AuctionKind::Sealed { .. }), EpochError::InvalidAuctionKind);
+        require!(slot >= self.end_slot, EpochError::RevealNotOpen);
+        require!(slot < self.settlement_slot()?, EpochError::RevealClosed);
+        Ok(())
+    }
+
+    /// Amount the winner pays, which is what gets distributed at settlement
+    pub fn clearing_price(&self) -> u64 {
+        match self.params.kind {
+            AuctionKind::Sealed { pricing: SealedBidPricing::SecondPrice, .. } => self
+                .second_bid_lamports
+                .max(self.params.reserve_price_lamports),
+            _ => self.high_bid_lamports,
+        }
+    }
+
+    pub fn has_bids(&self) -> bool {
+        self.high_bidder != Pubkey::default()
     }
 
     pub fn claim(&mut self) -> Result<()>{
         require!(self.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
+        require!(self.has_bids(), EpochError::NoBids);
         self.state = AuctionState::Claimed;
         Ok(())
     }
 
-    fn validate_bid(&self, bid_amount_lamports: u64) -> Result<()> {
-        let min_bid = self.high_bid_lamports + 10_000_000;
+    pub fn mark_unsold(&mut self) -> Result<()> {
+        require!(self.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
+        require!(!self.has_bids(), EpochError::AuctionHasBids);
+        self.state = AuctionState::Unsold;
+        Ok(())
+    }
+
+    fn validate_bid(&self, bid_amount_lamports: u64, slot: u64) -> Result<()> {
+        require!(self.params.kind == AuctionKind::English, EpochError::InvalidAuctionKind);
+        require!(self.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
+        self.validate_active(slot)?;
+
+        // Each rule is checked on its own for a precise error; together they are `min_bid_over`
+        let high_bid = self.high_bid_lamports;
+        require!(bid_amount_lamports >= self.params.reserve_price_lamports, EpochError::BidBelowReserve);
+        require!(bid_amount_lamports >= self.min_increment_bid_over(high_bid)?, EpochError::BidBelowMinimumIncrement);
+        require!(bid_amount_lamports >= self.min_percentage_bid_over(high_bid)?, EpochError::BidBelowPercentageIncrement);
+        Ok(())
+    }
+
+    /// During the gate window, only bidders with enough reputation or a valid allowlist proof may bid
+    pub fn validate_gate(&self, bidder: &Pubkey, reputation: u64, allowlist_proof: &[[u8; 32]], slot: u64) -> Result<()> {
+        let params = &self.params;
+        let gate_end_slot = self
+            .start_slot
+            .checked_add(params.gate_window_slots)
+            .ok_or(EpochError::Overflow)?;
+        if slot >= gate_end_slot {
+            return Ok(());
+        }
+
+        let has_reputation = params.gate_min_reputation > 0 && reputation >= params.gate_min_reputation;
+        let is_allowlisted = params.has_allowlist()
+            && verify_merkle_proof(allowlist_proof, &params.gate_allowlist_root, allowlist_leaf(bidder));
+        require!(has_reputation || is_allowlisted, EpochError::BidderNotAllowed);
+        Ok(())
+    }
+
+    /// Smallest bid that satisfies every bidding rule against a high bid of `amount`
+    fn min_bid_over(&self, amount: u64) -> Result<u64> {
+        Ok(self
+            .min_increment_bid_over(amount)?
+            .max(self.min_percentage_bid_over(amount)?)
+            .max(self.params.reserve_price_lamports))
+    }
+
+    /// Smallest bid that beats `amount` by the minimum increment
+    fn min_increment_bid_over(&self, amount: u64) -> Result<u64> {
+        Ok(amount
+            .checked_add(self.params.min_increment_lamports)
+            .ok_or(EpochError::Overflow)?)
+    }
+
+    /// Smallest bid that beats `amount` by the minimum percentage increment
+    fn min_percentage_bid_over(&self, amount: u64) -> Result<u64> {
+        let percentage_increment = (amount as u128)
+            .checked_mul(self.params.min_increment_bps as u128)
+            .ok_or(EpochError::Overflow)?
+            / BPS_DENOMINATOR as u128;
+        let min_bid = (amount as u128)
+            .checked_add(percentage_increment)
+            .ok_or(EpochError::Overflow)?;
+        Ok(u64::try_from(min_bid).map_err(|_| EpochError::Overflow)?)
+    }
+
+    /// Places a bid of `amount` backed by a hidden maximum of `max_amount` (equal to `amount` for a plain bid).
+    /// Competing proxies are resolved eBay-style: the higher maximum wins (ties go to the earlier bid)
+    /// and the visible bid is raised only as far as needed to beat the other side.
+    /// A leading bid takes `recipient` (the bidder if `None`); a high bidder raising their own bid keeps the
+    /// recipient they already named unless they name a new one.
+    pub fn bid(
+        &mut self,
+        bidder: Pubkey,
+        amount: u64,
+        max_amount: u64,
+        referrer: Pubkey,
+        recipient: Option<Pubkey>,
+        slot: u64,
+    ) -> Result<BidOutcome> {
+        self.validate_bid(amount, slot)?;
+        require!(max_amount >= amount, EpochError::InvalidMaxBid);
+
+        let previous_high_bidder = self.high_bidder;
+        let previous_max = self.proxy_max_lamports;
+
+        let outcome = if !self.has_bids() || previous_high_bidder == bidder {
+            // First bid, or the high bidder raising their own bid: their old escrow is returned in full
+            self.high_bid_lamports = amount;
+            self.high_bidder = bidder;
+            self.proxy_max_lamports = max_amount;
+            self.referrer = referrer;
+            // Nobody named a recipient before the first bid
+            self.recipient = recipient.unwrap_or(self.recipient);
+            BidOutcome {
+                is_high_bidder: true,
+                previous_high_bidder,
+                refund_lamports: previous_max,
+                escrow_lamports: max_amount,
+            }
+        } else if max_amount > previous_max {
+            // Outbids the previous proxy: pay just enough to beat it, capped at the new maximum
+            self.high_bid_lamports = amount.max(max_amount.min(self.min_bid_over(previous_max)?));
+            self.high_bidder = bidder;
+            self.proxy_max_lamports = max_amount;
+            self.referrer = referrer;
+            self.recipient = recipient.unwrap_or_default();
+            BidOutcome {
+                is_high_bidder: true,
+                previous_high_bidder,
+                refund_lamports: previous_max,
+                escrow_lamports: max_amount,
+            }
+        } else {
+            // The previous proxy still wins and answers the bid automatically
+            self.high_bid_lamports = previous_max.min(self.min_bid_over(max_amount)?);
+            BidOutcome {
+                is_high_bidder: false,
+                previous_high_bidder,
+                refund_lamports: 0,
+                escrow_lamports: 0,
+            }
+        };
+
+        self.extend_if_needed(slot)?;
+        Ok(outcome)
+    }
+
+    pub fn has_referrer(&self) -> bool {
+        self.referrer != Pubkey::default()
+    }
+
+    /// Hands an ended, unsettled win to `new_winner`. The old winner's unused proxy lamports stop being part
+    /// of the winning bid, so only the high bid has to move from their deposit to the new winner's.
+    /// English auctions only: sealed-bid and Dutch settlement pay out of the original winner's own deposit.
+    pub fn transfer_win(&mut self, winner: Pubkey, new_winner: Pubkey, slot: u64) -> Result<()> {
+        require!(self.params.kind == AuctionKind::English, EpochError::InvalidAuctionKind);
+        self.validate_ended(slot)?;
         require!(self.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
-        require!(bid_amount_lamports >= min_bid, EpochError::BidTooLow);
+        require!(self.has_bids() && self.high_bidder == winner, EpochError::InvalidWinner);
+        require!(new_winner != winner && new_winner != Pubkey::default(), EpochError::InvalidNewWinner);
+
+        self.proxy_max_lamports = self.proxy_max_lamports.min(self.high_bid_lamports);
+        self.high_bidder = new_winner;
+        self.recipient = Pubkey::default();
+        Ok(())
+    }
+
+    pub fn record_deposit_opened(&mut self) -> Result<()> {
+        self.open_deposits = self.open_deposits.checked_add(1).ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn record_deposit_released(&mut self) -> Result<()> {
+        self.open_deposits = self.open_deposits.checked_sub(1).ok_or(EpochError::Underflow)?;
+        Ok(())
+    }
+
+    pub fn record_sealed_bid_committed(&mut self) -> Result<()> {
+        self.unresolved_sealed_bids = self.unresolved_sealed_bids.checked_add(1).ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn record_sealed_bid_resolved(&mut self) -> Result<()> {
+        self.unresolved_sealed_bids = self.unresolved_sealed_bids.checked_sub(1).ok_or(EpochError::Underflow)?;
+        Ok(())
+    }
+
+    /// Wallet that receives the asset: the named recipient, or the winner if none was named
+    pub fn asset_recipient(&self) -> Pubkey {
+        if self.recipient == Pubkey::default() {
+            self.high_bidder
+        } else {
+            self.recipient
+        }
+    }
+
+    /// Gives other bidders time to respond to a bid placed in the last moments of the auction
+    fn extend_if_needed(&mut self, slot: u64) -> Result<()> {
+        let window = self.params.extension_window_slots;
+        let extended_end_slot = slot.checked_add(window).ok_or(EpochError::Overflow)?;
+        if extended_end_slot > self.end_slot {
+            self.end_slot = extended_end_slot;
+        }
         Ok(())
     }
 
-    pub fn bid(&mut self, bidder: Pubkey, amount: u64) -> Result<()> {
-        self.validate_bid(amount)?;
-        self.high_bid_lamports = amount;
-        self.high_bidder = bidder;
+    /// Records a revealed sealed bid. Ties go to the earlier reveal.
+    pub fn reveal(&mut self, bidder: Pubkey, amount: u64, slot: u64) -> Result<()> {
+        require!(self.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
+        self.validate_reveal_open(slot)?;
+        require!(amount >= self.params.reserve_price_lamports, EpochError::BidBelowReserve);
+
+        if !self.has_bids() || amount > self.high_bid_lamports {
+            self.second_bid_lamports = self.high_bid_lamports;
+            self.high_bid_lamports = amount;
+            self.high_bidder = bidder;
+        } else if amount > self.second_bid_lamports {
+            self.second_bid_lamports = amount;
+        }
         Ok(())
     }
+
+    /// Current price of a Dutch auction: decays linearly from `start_price` down to `floor_price`
+    pub fn dutch_price(&self, slot: u64) -> Result<u64> {
+        let (start_price, floor_price, decay_per_slot) = match self.params.kind {
+            AuctionKind::Dutch { start_price, floor_price, decay_per_slot } => (start_price, floor_price, decay_per_slot),
+            _ => return err!(EpochError::InvalidAuctionKind),
+        };
+        let elapsed_slots = slot.saturating_sub(self.start_slot);
+        let decay = elapsed_slots.saturating_mul(decay_per_slot);
+        Ok(start_price.saturating_sub(decay).max(floor_price))
+    }
+
+    /// Sells a Dutch auction to `buyer` at the current price and marks it claimed
+    pub fn buy_now(&mut self, buyer: Pubkey, recipient: Option<Pubkey>, slot: u64) -> Result<u64> {
+        require!(self.state == AuctionState::UnClaimed, EpochError::AuctionAlreadyClaimed);
+        self.validate_active(slot)?;
+        let price = self.dutch_price(slot)?;
+        self.high_bidder = buyer;
+        self.high_bid_lamports = price;
+        self.recipient = recipient.unwrap_or_default();
+        self.claim()?;
+        Ok(price)
+    }
 }
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::EpochError;
+
+/// Reputation tiers that can be shown as soulbound badges. Admin-controlled.
+/// Singleton seeded on `BADGE_CONFIG_SEED`, created alongside the badge group.
+#[account]
+pub struct BadgeConfig {
+    pub bump: u8,
+    /// Ordered from lowest to highest threshold; a badge's tier is its index here
+    pub tiers: Vec<BadgeTier>,
+    /// One past the highest tier any badge has been minted for; `tiers` can't shrink below it
+    pub minted_tiers: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct BadgeTier {
+    /// Shown in the badge name, e.g. "Epochs Reputation: Gold"
+    pub name: String,
+    /// Current (decayed) reputation needed to mint or upgrade to this tier
+    pub min_reputation: u64,
+}
+
+impl BadgeTier {
+    pub const MAX_NAME_LEN: usize = 16;
+
+    pub fn get_size() -> usize {
+        4 + Self::MAX_NAME_LEN + // name
+        8       // min_reputation
+    }
+}
+
+impl BadgeConfig {
+    pub const MAX_TIERS: usize = 5;
+
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        1 +     // bump
+        4 + Self::MAX_TIERS * BadgeTier::get_size() + // tiers
+        1       // minted_tiers
+    }
+
+    pub fn init(&mut self, tiers: Vec<BadgeTier>, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.minted_tiers = 0;
+        self.update(tiers)
+    }
+
+    /// Existing badges keep their tier index, so renaming or reordering tiers affects them on their next upgrade only.
+    /// Tiers up to the highest one minted can't be removed, so every badge's index stays valid.
+    pub fn update(&mut self, tiers: Vec<BadgeTier>) -> Result<()> {
+        Self::validate(&tiers)?;
+        require!(tiers.len() >= self.minted_tiers as usize, EpochError::MintedBadgeTierRemoved);
+        self.tiers = tiers;
+        Ok(())
+    }
+
+    fn validate(tiers: &[BadgeTier]) -> Result<()> {
+        require!(!tiers.is_empty() && tiers.len() <= Self::MAX_TIERS, EpochError::InvalidBadgeTiers);
+        require!(
+            tiers.iter().all(|tier| !tier.name.is_empty() && tier.name.len() <= BadgeTier::MAX_NAME_LEN),
+            EpochError::InvalidBadgeTiers
+        );
+        require!(
+            tiers.windows(2).all(|pair| pair[0].min_reputation < pair[1].min_reputation),
+            EpochError::InvalidBadgeTiers
+        );
+        Ok(())
+    }
+
+    /// Returns the tier at index `tier` if `reputation` qualifies for it
+    pub fn validate_tier(&self, tier: u8, reputation: u64) -> Result<&BadgeTier> {
+        let badge_tier = self.tiers.get(tier as usize).ok_or(EpochError::InvalidBadgeTier)?;
+        require!(reputation >= badge_tier.min_reputation, EpochError::InsufficientReputation);
+        Ok(badge_tier)
+    }
+
+    pub fn record_minted(&mut self, tier: u8) {
+        self.minted_tiers = self.minted_tiers.max(tier + 1);
+    }
+}
+
+/// A contributor's current reputation badge. Seeded on the contributor's pubkey.
+#[account]
+pub struct Badge {
+    pub contributor: Pubkey,
+    /// The soulbound Nifty asset, seeded on `BADGE_ASSET_SEED`, the contributor and the tier
+    pub asset: Pubkey,
+    /// Index into `BadgeConfig::tiers`
+    pub tier: u8,
+    pub bump: u8,
+}
+
+impl Badge {
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        32 +    // contributor
+        32 +    // asset
+        1 +     // tier
+        1       // bump
+    }
+
+    pub fn init(&mut self, contributor: Pubkey, asset: Pubkey, tier: u8, bump: u8) {
+        self.contributor = contributor;
+        self.asset = asset;
+        self.tier = tier;
+        self.bump = bump;
+    }
+
+    /// Moves the badge to a higher tier, returning the asset it replaces
+    pub fn upgrade(&mut self, asset: Pubkey, tier: u8) -> Result<Pubkey> {
+        require!(tier > self.tier, EpochError::InvalidBadgeTier);
+        let previous_asset = self.asset;
+        self.asset = asset;
+        self.tier = tier;
+        Ok(previous_asset)
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::EpochError;
+
+/// Lamports owed to a bidder that are still held in the auction escrow (e.g., after being outbid).
+/// Seeded on the bidder's pubkey. Can be withdrawn or applied toward a new bid.
+#[account]
+pub struct BidCredit {
+    pub bidder: Pubkey,
+    pub lamports: u64,
+    pub initialized: bool,
+    pub bump: u8,
+    /// Set on the owner's first bid or won auction, so referrer-only credits are not counted as bidders
+    pub has_bid: bool,
+}
+
+impl BidCredit {
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        32 +    // bidder
+        8 +     // lamports
+        1 +     // initialized
+        1 +     // bump
+        1       // has_bid
+    }
+
+    pub fn init_if_needed(&mut self, bidder: Pubkey, bump: u8) {
+        if !self.initialized {
+            self.bidder = bidder;
+            self.lamports = 0;
+            self.initialized = true;
+            self.bump = bump;
+            self.has_bid = false;
+        }
+    }
+
+    /// Marks the owner as a bidder. Returns true the first time, when `Stats::unique_bidders` should count them.
+    pub fn record_bid(&mut self) -> bool {
+        let is_new_bidder = !self.has_bid;
+        self.has_bid = true;
+        is_new_bidder
+    }
+
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.lamports = self
+            .lamports
+            .checked_add(amount)
+            .ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.lamports = self
+            .lamports
+            .checked_sub(amount)
+            .ok_or(EpochError::InsufficientCredit)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{Auction, AuctionState, EpochError};
+
+/// What a bidder has escrowed in one English auction.
+/// Seeded on the epoch and the bidder's pubkey. Outbidding somebody moves nothing: once their deposit
+/// no longer backs the leading bid, anybody can `release_bid_deposit` it into their `BidCredit`.
+#[account]
+pub struct BidDeposit {
+    pub epoch: u64,
+    pub bidder: Pubkey,
+    /// Lamports (or base units of the bid mint) held in escrow for the bidder
+    pub amount: u64,
+    pub initialized: bool,
+    pub bump: u8,
+}
+
+impl BidDeposit {
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        8 +     // epoch
+        32 +    // bidder
+        8 +     // amount
+        1 +     // initialized
+        1       // bump
+    }
+
+    /// Returns whether the deposit was just created
+    pub fn init_if_needed(&mut self, epoch: u64, bidder: Pubkey, bump: u8) -> bool {
+        if self.initialized {
+            return false;
+        }
+        self.epoch = epoch;
+        self.bidder = bidder;
+        self.amount = 0;
+        self.initialized = true;
+        self.bump = bump;
+        true
+    }
+
+    /// Amount that is no longer backing a bid and can be returned to the bidder.
+    /// The winner's deposit pays the price once the auction is claimed; only what is left over is releasable.
+    pub fn releasable(&self, auction: &Auction) -> Result<u64> {
+        if self.bidder != auction.high_bidder {
+            return Ok(self.amount);
+        }
+        require!(auction.state == AuctionState::Claimed, EpochError::BidStillLeading);
+        self.amount
+            .checked_sub(auction.clearing_price())
+            .ok_or_else(|| EpochError::Underflow.into())
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::EpochError;
+
+/// Number of records kept in a `BidHistory`; a free constant so it can size the array
+pub const BID_HISTORY_CAPACITY: usize = 64;
+
+/// The most recent bids on an epoch's auction.
+/// Seeded on `BID_HISTORY_SEED` and the epoch. Zero-copy ring buffer: once full, each new bid
+/// overwrites the oldest record, so clients can fetch the bid ladder in a single account read
+/// (the oldest record is at `total_bids % CAPACITY` once the buffer has wrapped).
+#[account(zero_copy)]
+pub struct BidHistory {
+    pub epoch: u64,
+    /// Bids ever recorded; the next write goes to `total_bids % CAPACITY`
+    pub total_bids: u64,
+    pub bump: u8,
+    pub _padding: [u8; 7],
+    pub bids: [BidRecord; BID_HISTORY_CAPACITY],
+}
+
+/// The standing high bid right after a bid was placed. When an existing proxy bid immediately beats the new bid,
+/// this is the proxy holder at their raised price, so the ladder only shows prices that actually led.
+#[zero_copy]
+pub struct BidRecord {
+    pub bidder: Pubkey,
+    pub lamports: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+impl BidHistory {
+    pub const CAPACITY: usize = BID_HISTORY_CAPACITY;
+
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        8 +     // epoch
+        8 +     // total_bids
+        1 +     // bump
+        7 +     // padding
+        Self::CAPACITY * (
+            32 +    // bidder
+            8 +     // lamports
+            8 +     // slot
+            8       // timestamp
+        )
+    }
+
+    pub fn init(&mut self, epoch: u64, bump: u8) {
+        self.epoch = epoch;
+        self.total_bids = 0;
+        self.bump = bump;
+    }
+
+    pub fn append(&mut self, bidder: Pubkey, lamports: u64, clock: &Clock) -> Result<()> {
+        let index = (self.total_bids % Self::CAPACITY as u64) as usize;
+        self.bids[index] = BidRecord {
+            bidder,
+            lamports,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        };
+        self.total_bids = self.total_bids.checked_add(1).ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::{AuctionParams, EpochError, ReputationParams, BPS_DENOMINATOR};
+
+/// Global, admin-controlled program settings.
+/// Singleton seeded on `CONFIG_SEED`. Replaces the wallets and shares that used to be compiled in.
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub dao_treasury: Pubkey,
+    pub treasury_share_bps: u16,
+    pub creators: Vec<CreatorShare>,
+    pub royalty_bps: u16,
+    pub bump: u8,
+    /// Applied to auctions created from now on; live auctions keep their own snapshot
+    pub auction_params: AuctionParams,
+    /// Share of the sale price paid to the frontend that referred the winning bid, before the treasury/creator split
+    pub referral_bps: u16,
+    pub reputation_params: ReputationParams,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct CreatorShare {
+    pub address: Pubkey,
+    pub share_bps: u16,
+}
+
+impl CreatorShare {
+    pub fn get_size() -> usize {
+        32 +    // address
+        2       // share_bps
+    }
+}
+
+/// Arguments for `initialize_config` and `update_config`.
+/// Every field is overwritten on update, so clients should send the full desired config.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigArgs {
+    pub admin: Pubkey,
+    pub dao_treasury: Pubkey,
+    pub treasury_share_bps: u16,
+    pub creators: Vec<CreatorShare>,
+    pub royalty_bps: u16,
+    pub auction_params: AuctionParams,
+    pub referral_bps: u16,
+    pub reputation_params: ReputationParams,
+}
+
+impl Config {
+    pub const MAX_CREATORS: usize = 5;
+
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        32 +    // admin
+        32 +    // dao_treasury
+        2 +     // treasury_share_bps
+        4 + Self::MAX_CREATORS * CreatorShare::get_size() + // creators
+        2 +     // royalty_bps
+        1 +     // bump
+        AuctionParams::get_size() + // auction_params
+        2 +     // referral_bps
+        ReputationParams::get_size() // reputation_params
+    }
+
+    pub fn init(&mut self, args: ConfigArgs, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.update(args)
+    }
+
+    pub fn update(&mut self, args: ConfigArgs) -> Result<()> {
+        Self::validate(&args)?;
+        self.admin = args.admin;
+        self.dao_treasury = args.dao_treasury;
+        self.treasury_share_bps = args.treasury_share_bps;
+        self.creators = args.creators;
+        self.royalty_bps = args.royalty_bps;
+        self.auction_params = args.auction_params;
+        self.referral_bps = args.referral_bps;
+        self.reputation_params = args.reputation_params;
+        Ok(())
+    }
+
+    fn validate(args: &ConfigArgs) -> Result<()> {
+        require!(args.creators.len() <= Self::MAX_CREATORS, EpochError::TooManyCreators);
+        require!(args.royalty_bps as u64 <= BPS_DENOMINATOR, EpochError::InvalidBasisPoints);
+        require!(args.referral_bps as u64 <= BPS_DENOMINATOR, EpochError::InvalidBasisPoints);
+        args.auction_params.validate()?;
+
+        // Treasury + creators must account for the full sale price
+        let total_bps = args
+            .creators
+            .iter()
+            .try_fold(args.treasury_share_bps as u64, |total, creator| total.checked_add(creator.share_bps as u64))
+            .ok_or(EpochError::Overflow)?;
+        require!(total_bps == BPS_DENOMINATOR, EpochError::InvalidBasisPoints);
+
+        // The group's Nifty creator shares are whole percentages
+        require!(
+            args.treasury_share_bps % 100 == 0 && args.creators.iter().all(|creator| creator.share_bps % 100 == 0),
+            EpochError::InvalidBasisPoints
+        );
+        Ok(())
+    }
+
+    /// Referrer's cut of `amount`, taken before `split`
+    pub fn referral_fee(&self, amount: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(self.referral_bps as u128)
+            .ok_or(EpochError::Overflow)?
+            / BPS_DENOMINATOR as u128;
+        Ok(fee as u64)
+    }
+
+    /// Splits `amount` between the creators (in config order) and the DAO treasury.
+    /// Rounding dust is left with the treasury.
+    pub fn split(&self, amount: u64) -> Result<(u64, Vec<u64>)> {
+        let mut creator_amounts = Vec::with_capacity(self.creators.len());
+        let mut remaining = amount;
+        for creator in self.creators.iter() {
+            let share = (amount as u128)
+                .checked_mul(creator.share_bps as u128)
+                .ok_or(EpochError::Overflow)?
+                / BPS_DENOMINATOR as u128;
+            let share = share as u64;
+            remaining = remaining.checked_sub(share).ok_or(EpochError::Underflow)?;
+            creator_amounts.push(share);
+        }
+        Ok((remaining, creator_amounts))
+    }
+}
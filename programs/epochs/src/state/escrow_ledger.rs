@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::EpochError;
+
+/// Solvency accounting for the shared auction escrow.
+/// Singleton seeded on `ESCROW_LEDGER_SEED`. Tracks every lamport the escrow owes
+/// (live high bids and unwithdrawn bid credit) so anything above that can be swept to the treasury.
+#[account]
+pub struct EscrowLedger {
+    pub total_outstanding_lamports: u64,
+    pub bump: u8,
+}
+
+impl EscrowLedger {
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        8 +     // total_outstanding_lamports
+        1       // bump
+    }
+
+    pub fn init(&mut self, bump: u8) {
+        self.total_outstanding_lamports = 0;
+        self.bump = bump;
+    }
+
+    /// Records lamports entering the escrow that are owed to somebody
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_outstanding_lamports = self
+            .total_outstanding_lamports
+            .checked_add(amount)
+            .ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    /// Records owed lamports leaving the escrow
+    pub fn withdraw(&mut self, amount: u64) -> Result<()> {
+        self.total_outstanding_lamports = self
+            .total_outstanding_lamports
+            .checked_sub(amount)
+            .ok_or(EpochError::Underflow)?;
+        Ok(())
+    }
+
+    /// Returns the lamports held beyond what is owed and the escrow's rent-exempt minimum
+    pub fn surplus(&self, escrow_lamports: u64, rent_exempt_minimum: u64) -> Result<u64> {
+        require!(escrow_lamports >= self.total_outstanding_lamports, EpochError::EscrowInsolvent);
+        Ok(escrow_lamports
+            .saturating_sub(self.total_outstanding_lamports)
+            .saturating_sub(rent_exempt_minimum))
+    }
+}
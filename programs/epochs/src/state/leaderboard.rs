@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// Number of entries kept in the `Leaderboard`; a free constant so it can size the array
+pub const LEADERBOARD_CAPACITY: usize = 50;
+
+/// The top contributors by reputation, highest first.
+/// Singleton seeded on `LEADERBOARD_SEED`. Updated whenever an instruction changes a contributor's reputation;
+/// since decay is applied lazily, entries reflect each contributor's reputation as of their last update
+/// and can be refreshed with `sync_leaderboard`.
+#[account(zero_copy)]
+pub struct Leaderboard {
+    /// Number of filled entries
+    pub len: u64,
+    pub bump: u8,
+    pub _padding: [u8; 7],
+    pub entries: [LeaderboardEntry; LEADERBOARD_CAPACITY],
+}
+
+#[zero_copy]
+pub struct LeaderboardEntry {
+    pub contributor: Pubkey,
+    pub reputation: u64,
+}
+
+impl Leaderboard {
+    pub const CAPACITY: usize = LEADERBOARD_CAPACITY;
+
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        8 +     // len
+        1 +     // bump
+        7 +     // padding
+        Self::CAPACITY * (
+            32 +    // contributor
+            8       // reputation
+        )
+    }
+
+    pub fn init(&mut self, bump: u8) {
+        self.len = 0;
+        self.bump = bump;
+    }
+
+    /// Moves `contributor` to its place for `reputation`, inserting it if it makes the board.
+    /// A full board evicts its lowest entry; ties keep the contributor already on the board ahead.
+    pub fn update(&mut self, contributor: Pubkey, reputation: u64) {
+        let mut len = self.len as usize;
+        if let Some(current) = self.entries[..len].iter().position(|entry| entry.contributor == contributor) {
+            self.entries.copy_within(current + 1..len, current);
+            len -= 1;
+        }
+
+        let index = self.entries[..len]
+            .iter()
+            .position(|entry| entry.reputation < reputation)
+            .unwrap_or(len);
+        if reputation == 0 || index == Self::CAPACITY {
+            self.len = len as u64;
+            return;
+        }
+
+        let end = len.min(Self::CAPACITY - 1);
+        self.entries.copy_within(index..end, index + 1);
+        self.entries[index] = LeaderboardEntry { contributor, reputation };
+        self.len = (end + 1) as u64;
+    }
+}
@@ -1,5 +1,27 @@
 pub mod auction;
 pub mod reputation;
+pub mod config;
+pub mod bid_credit;
+pub mod bid_deposit;
+pub mod token_credit;
+pub mod escrow_ledger;
+pub mod sealed_bid;
+pub mod bid_history;
+pub mod stats;
+pub mod points_table;
+pub mod badge;
+pub mod leaderboard;
 
 pub use auction::*;
-pub use reputation::*;
\ No newline at end of file
+pub use reputation::*;
+pub use config::*;
+pub use bid_credit::*;
+pub use bid_deposit::*;
+pub use token_credit::*;
+pub use escrow_ledger::*;
+pub use sealed_bid::*;
+pub use bid_history::*;
+pub use stats::*;
+pub use points_table::*;
+pub use badge::*;
+pub use leaderboard::*;
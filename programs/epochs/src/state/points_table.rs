@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+use crate::{EpochError, BPS_DENOMINATOR};
+
+/// Reputation points awarded for each action. Admin-controlled so points can be tuned and promotions run
+/// without a program upgrade. Singleton seeded on `POINTS_TABLE_SEED`.
+#[account]
+pub struct PointsTable {
+    pub bump: u8,
+    pub initiate: u64,
+    pub bid: u64,
+    pub win: u64,
+    pub settle: u64,
+    pub force_settle: u64,
+    /// Extra bid points for every this many lamports bid (0 to disable size-based points)
+    pub bid_lamports_per_point: u64,
+    /// Cap on the size-based bid points
+    pub max_bid_size_points: u64,
+    /// Epochs divisible by this are milestones (0 to disable)
+    pub milestone_interval_epochs: u64,
+    /// Multiplier on all points earned on a milestone epoch's auction, in bps (20,000 = double points)
+    pub milestone_multiplier_bps: u16,
+}
+
+/// Arguments for `initialize_points_table` and `update_points_table`.
+/// Every field is overwritten on update, so clients should send the full desired table.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PointsTableArgs {
+    pub initiate: u64,
+    pub bid: u64,
+    pub win: u64,
+    pub settle: u64,
+    pub force_settle: u64,
+    pub bid_lamports_per_point: u64,
+    pub max_bid_size_points: u64,
+    pub milestone_interval_epochs: u64,
+    pub milestone_multiplier_bps: u16,
+}
+
+impl PointsTable {
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        1 +     // bump
+        8 +     // initiate
+        8 +     // bid
+        8 +     // win
+        8 +     // settle
+        8 +     // force_settle
+        8 +     // bid_lamports_per_point
+        8 +     // max_bid_size_points
+        8 +     // milestone_interval_epochs
+        2       // milestone_multiplier_bps
+    }
+
+    pub fn init(&mut self, args: PointsTableArgs, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.update(args)
+    }
+
+    pub fn update(&mut self, args: PointsTableArgs) -> Result<()> {
+        require!(
+            args.milestone_interval_epochs == 0 || args.milestone_multiplier_bps > 0,
+            EpochError::InvalidPointsTable
+        );
+        self.initiate = args.initiate;
+        self.bid = args.bid;
+        self.win = args.win;
+        self.settle = args.settle;
+        self.force_settle = args.force_settle;
+        self.bid_lamports_per_point = args.bid_lamports_per_point;
+        self.max_bid_size_points = args.max_bid_size_points;
+        self.milestone_interval_epochs = args.milestone_interval_epochs;
+        self.milestone_multiplier_bps = args.milestone_multiplier_bps;
+        Ok(())
+    }
+
+    pub fn initiate_points(&self, epoch: u64) -> Result<u64> {
+        self.scale(self.initiate, epoch)
+    }
+
+    /// Base bid points plus one point per `bid_lamports_per_point` lamports, capped at `max_bid_size_points`.
+    /// Pass 0 lamports when the bid size should not count (token auctions, sealed commitments).
+    pub fn bid_points(&self, epoch: u64, bid_lamports: u64) -> Result<u64> {
+        let size_points = bid_lamports
+            .checked_div(self.bid_lamports_per_point)
+            .unwrap_or(0)
+            .min(self.max_bid_size_points);
+        let points = self.bid.checked_add(size_points).ok_or(EpochError::Overflow)?;
+        self.scale(points, epoch)
+    }
+
+    pub fn win_points(&self, epoch: u64) -> Result<u64> {
+        self.scale(self.win, epoch)
+    }
+
+    pub fn settle_points(&self, epoch: u64) -> Result<u64> {
+        self.scale(self.settle, epoch)
+    }
+
+    pub fn force_settle_points(&self, epoch: u64) -> Result<u64> {
+        self.scale(self.force_settle, epoch)
+    }
+
+    pub fn is_milestone(&self, epoch: u64) -> bool {
+        self.milestone_interval_epochs > 0 && epoch % self.milestone_interval_epochs == 0
+    }
+
+    /// Applies the milestone multiplier when `epoch` is a milestone
+    fn scale(&self, points: u64, epoch: u64) -> Result<u64> {
+        if !self.is_milestone(epoch) {
+            return Ok(points);
+        }
+        let scaled = (points as u128)
+            .checked_mul(self.milestone_multiplier_bps as u128)
+            .ok_or(EpochError::Overflow)?
+            / BPS_DENOMINATOR as u128;
+        u64::try_from(scaled).map_err(|_| EpochError::Overflow.into())
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::EpochError;
+use crate::{EpochError, BPS_DENOMINATOR};
 
 #[account]
 pub struct Reputation {
@@ -8,6 +8,53 @@ pub struct Reputation {
     pub reputation: u64,
     pub initialized: bool,
     pub bump: u8,
+    /// Last epoch with a bid or initiation
+    pub last_active_epoch: u64,
+    /// Consecutive epochs with a bid or initiation, ending at `last_active_epoch`
+    pub streak: u64,
+    /// Epoch decay was last applied up to (0 until the account is first touched after decay was introduced)
+    pub last_decay_epoch: u64,
+    /// Lifetime count and points (before decay) for each kind of action.
+    /// Accounts migrated from an older layout only count actions from the migration on.
+    pub initiations: u64,
+    pub initiation_points: u64,
+    pub bids: u64,
+    pub bid_points: u64,
+    pub wins: u64,
+    pub win_points: u64,
+    pub settles: u64,
+    pub settle_points: u64,
+    /// Lamports offered across every accepted open bid in lamport auctions (sealed bids are hidden when committed)
+    pub total_bid_lamports: u64,
+}
+
+/// The action reputation is awarded for, used to keep the per-action breakdown
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ReputationAction {
+    Initiate,
+    Bid,
+    Win,
+    /// Includes force settles
+    Settle,
+}
+
+/// How reputation decays and how streaks are rewarded. Configured globally on `Config`.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ReputationParams {
+    /// Reputation halves every this many epochs (0 to disable decay)
+    pub half_life_epochs: u64,
+    /// Bonus on streak actions for every consecutive active epoch after the first, in bps of the base points
+    pub streak_bonus_bps: u16,
+    /// Cap on the streak bonus, in bps of the base points
+    pub max_streak_bonus_bps: u16,
+}
+
+impl ReputationParams {
+    pub fn get_size() -> usize {
+        8 +     // half_life_epochs
+        2 +     // streak_bonus_bps
+        2       // max_streak_bonus_bps
+    }
 }
 
 impl Reputation {
@@ -16,16 +63,108 @@ impl Reputation {
         32 +    // contributor
         8 +     // reputation
         1 +     // initialized
-        1       // bump
+        1 +     // bump
+        8 +     // last_active_epoch
+        8 +     // streak
+        8 +     // last_decay_epoch
+        8 +     // initiations
+        8 +     // initiation_points
+        8 +     // bids
+        8 +     // bid_points
+        8 +     // wins
+        8 +     // win_points
+        8 +     // settles
+        8 +     // settle_points
+        8       // total_bid_lamports
     }
 
-    // Updated increment_with_validation method to use Result for error handling
-    pub fn increment_with_validation(&mut self, amount: u64, contributor: Pubkey) -> Result<()> {
+    /// Decays the reputation, then adds `amount`. Returns the points added.
+    pub fn increment_with_validation(&mut self, action: ReputationAction, amount: u64, contributor: Pubkey, params: &ReputationParams, epoch: u64) -> Result<u64> {
         require!(self.validate(contributor), EpochError::InvalidContributor);
+        self.apply_decay(params, epoch);
+        self.add(action, amount)
+    }
+
+    /// Like `increment_with_validation`, for actions that count towards the activity streak (bids and initiations).
+    /// The streak is updated first and its bonus added on top of `amount`. Returns the points added.
+    pub fn increment_with_streak(&mut self, action: ReputationAction, amount: u64, contributor: Pubkey, params: &ReputationParams, epoch: u64) -> Result<u64> {
+        require!(self.validate(contributor), EpochError::InvalidContributor);
+        self.apply_decay(params, epoch);
+        self.record_activity(epoch)?;
+
+        let bonus_bps = (self.streak - 1)
+            .saturating_mul(params.streak_bonus_bps as u64)
+            .min(params.max_streak_bonus_bps as u64);
+        let bonus = (amount as u128)
+            .checked_mul(bonus_bps as u128)
+            .ok_or(EpochError::Overflow)?
+            / BPS_DENOMINATOR as u128;
+        let points = amount.checked_add(bonus as u64).ok_or(EpochError::Overflow)?;
+        self.add(action, points)
+    }
+
+    /// Adds to the lifetime lamports bid. Called for every accepted bid, whether or not it earns reputation.
+    pub fn record_bid_lamports(&mut self, lamports: u64) -> Result<()> {
+        self.total_bid_lamports = self
+            .total_bid_lamports
+            .checked_add(lamports)
+            .ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    fn add(&mut self, action: ReputationAction, points: u64) -> Result<u64> {
         self.reputation = self
             .reputation
-            .checked_add(amount)
+            .checked_add(points)
             .ok_or_else(|| EpochError::Overflow)?;
+
+        let (count, subtotal) = match action {
+            ReputationAction::Initiate => (&mut self.initiations, &mut self.initiation_points),
+            ReputationAction::Bid => (&mut self.bids, &mut self.bid_points),
+            ReputationAction::Win => (&mut self.wins, &mut self.win_points),
+            ReputationAction::Settle => (&mut self.settles, &mut self.settle_points),
+        };
+        *count = count.checked_add(1).ok_or(EpochError::Overflow)?;
+        *subtotal = subtotal.checked_add(points).ok_or(EpochError::Overflow)?;
+        Ok(points)
+    }
+
+    /// Reputation as of `epoch`, with any pending decay applied. Does not modify the account.
+    pub fn current_reputation(&self, params: &ReputationParams, epoch: u64) -> u64 {
+        let half_lives = self.pending_half_lives(params, epoch);
+        self.reputation.checked_shr(half_lives.min(u32::MAX as u64) as u32).unwrap_or(0)
+    }
+
+    fn pending_half_lives(&self, params: &ReputationParams, epoch: u64) -> u64 {
+        if params.half_life_epochs == 0 || self.last_decay_epoch == 0 || epoch < self.last_decay_epoch {
+            return 0;
+        }
+        (epoch - self.last_decay_epoch) / params.half_life_epochs
+    }
+
+    /// Halves the reputation once for every full half-life since decay was last applied.
+    /// Leftover epochs carry over to the next touch.
+    fn apply_decay(&mut self, params: &ReputationParams, epoch: u64) {
+        if params.half_life_epochs == 0 || self.last_decay_epoch == 0 || epoch < self.last_decay_epoch {
+            self.last_decay_epoch = epoch;
+            return;
+        }
+
+        let half_lives = self.pending_half_lives(params, epoch);
+        self.reputation = self.current_reputation(params, epoch);
+        self.last_decay_epoch += half_lives * params.half_life_epochs;
+    }
+
+    fn record_activity(&mut self, epoch: u64) -> Result<()> {
+        if self.streak > 0 && self.last_active_epoch == epoch {
+            return Ok(());
+        }
+        self.streak = if self.streak > 0 && self.last_active_epoch.checked_add(1) == Some(epoch) {
+            self.streak.checked_add(1).ok_or(EpochError::Overflow)?
+        } else {
+            1
+        };
+        self.last_active_epoch = epoch;
         Ok(())
     }
 
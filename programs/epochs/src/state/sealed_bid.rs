@@ -0,0 +1,54 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+use crate::EpochError;
+
+/// A bidder's commitment in a sealed-bid auction.
+/// Seeded on the auction epoch and the bidder's pubkey. Closed by `resolve_sealed_bid` once the auction is over.
+#[account]
+pub struct SealedBid {
+    pub epoch: u64,
+    pub bidder: Pubkey,
+    /// keccak256(bid_lamports (u64 LE) || salt || bidder)
+    pub commitment: [u8; 32],
+    /// Lamports escrowed with the commitment. The revealed bid can not exceed it.
+    pub deposit_lamports: u64,
+    pub revealed: bool,
+    pub bid_lamports: u64,
+    pub bump: u8,
+}
+
+impl SealedBid {
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        8 +     // epoch
+        32 +    // bidder
+        32 +    // commitment
+        8 +     // deposit_lamports
+        1 +     // revealed
+        8 +     // bid_lamports
+        1       // bump
+    }
+
+    pub fn hash(bid_lamports: u64, salt: &[u8; 32], bidder: &Pubkey) -> [u8; 32] {
+        keccak::hashv(&[&bid_lamports.to_le_bytes(), salt, bidder.as_ref()]).to_bytes()
+    }
+
+    pub fn commit(&mut self, epoch: u64, bidder: Pubkey, commitment: [u8; 32], deposit_lamports: u64, bump: u8) {
+        self.epoch = epoch;
+        self.bidder = bidder;
+        self.commitment = commitment;
+        self.deposit_lamports = deposit_lamports;
+        self.revealed = false;
+        self.bid_lamports = 0;
+        self.bump = bump;
+    }
+
+    pub fn reveal(&mut self, bid_lamports: u64, salt: &[u8; 32]) -> Result<()> {
+        require!(!self.revealed, EpochError::BidAlreadyRevealed);
+        require!(Self::hash(bid_lamports, salt, &self.bidder) == self.commitment, EpochError::InvalidReveal);
+        require!(bid_lamports <= self.deposit_lamports, EpochError::BidExceedsDeposit);
+        self.revealed = true;
+        self.bid_lamports = bid_lamports;
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::{utils::Distribution, EpochError};
+
+/// Protocol-wide totals for dashboards.
+/// Singleton seeded on `STATS_SEED`. Lamport totals only include lamport auctions.
+/// `treasury_lamports` also includes forfeited sealed-bid deposits and swept escrow surplus.
+#[account]
+pub struct Stats {
+    pub auctions_created: u64,
+    /// Auctions sold (claimed, settled or bought); unsold auctions are not counted
+    pub auctions_settled: u64,
+    pub total_raised_lamports: u64,
+    pub treasury_lamports: u64,
+    pub creator_lamports: u64,
+    pub referral_lamports: u64,
+    pub highest_price_lamports: u64,
+    pub highest_price_epoch: u64,
+    /// Wallets that have ever bid, bought or taken over a win (counted on their `BidCredit`'s first bid)
+    pub unique_bidders: u64,
+    pub bump: u8,
+}
+
+impl Stats {
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        8 +     // auctions_created
+        8 +     // auctions_settled
+        8 +     // total_raised_lamports
+        8 +     // treasury_lamports
+        8 +     // creator_lamports
+        8 +     // referral_lamports
+        8 +     // highest_price_lamports
+        8 +     // highest_price_epoch
+        8 +     // unique_bidders
+        1       // bump
+    }
+
+    pub fn init(&mut self, bump: u8) {
+        self.bump = bump;
+    }
+
+    pub fn record_auction_created(&mut self) -> Result<()> {
+        self.auctions_created = self.auctions_created.checked_add(1).ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn record_new_bidder(&mut self) -> Result<()> {
+        self.unique_bidders = self.unique_bidders.checked_add(1).ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    /// Lamports sent to the DAO treasury outside of a sale
+    pub fn record_treasury_lamports(&mut self, amount: u64) -> Result<()> {
+        self.treasury_lamports = self.treasury_lamports.checked_add(amount).ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+
+    /// Records a sale. Token auction amounts are not added to the lamport totals.
+    pub fn record_sale(&mut self, epoch: u64, price: u64, distribution: &Distribution, is_lamports: bool) -> Result<()> {
+        self.auctions_settled = self.auctions_settled.checked_add(1).ok_or(EpochError::Overflow)?;
+        if !is_lamports {
+            return Ok(());
+        }
+
+        let creator_lamports = distribution
+            .creator_amounts
+            .iter()
+            .try_fold(0u64, |total, &amount| total.checked_add(amount))
+            .ok_or(EpochError::Overflow)?;
+
+        self.total_raised_lamports = self.total_raised_lamports.checked_add(price).ok_or(EpochError::Overflow)?;
+        self.treasury_lamports = self
+            .treasury_lamports
+            .checked_add(distribution.treasury_amount)
+            .ok_or(EpochError::Overflow)?;
+        self.creator_lamports = self.creator_lamports.checked_add(creator_lamports).ok_or(EpochError::Overflow)?;
+        self.referral_lamports = self
+            .referral_lamports
+            .checked_add(distribution.referral_amount)
+            .ok_or(EpochError::Overflow)?;
+
+        if price > self.highest_price_lamports {
+            self.highest_price_lamports = price;
+            self.highest_price_epoch = epoch;
+        }
+        Ok(())
+    }
+}
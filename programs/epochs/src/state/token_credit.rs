@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::EpochError;
+
+/// Tokens of one bid mint owed to a wallet (e.g., a released bid deposit), held in the mint's token credit vault.
+/// Seeded on the mint and the owner's pubkey. Withdrawn by the owner with `withdraw_token_credit`.
+#[account]
+pub struct TokenCredit {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    /// Base units of `mint`
+    pub amount: u64,
+    pub initialized: bool,
+    pub bump: u8,
+}
+
+impl TokenCredit {
+    pub fn get_size() -> usize {
+        8 +     // discriminator
+        32 +    // owner
+        32 +    // mint
+        8 +     // amount
+        1 +     // initialized
+        1       // bump
+    }
+
+    pub fn init_if_needed(&mut self, owner: Pubkey, mint: Pubkey, bump: u8) {
+        if !self.initialized {
+            self.owner = owner;
+            self.mint = mint;
+            self.amount = 0;
+            self.initialized = true;
+            self.bump = bump;
+        }
+    }
+
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self
+            .amount
+            .checked_add(amount)
+            .ok_or(EpochError::Overflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::{AccountMeta, Instruction}, program::invoke_signed},
+    event::EVENT_IX_TAG_LE,
+    Event,
+};
+
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Same as `emit_cpi!`, which can only be used where `ctx` is in scope.
+/// Requires the `event_authority` account added by `#[event_cpi]` and its bump.
+pub fn emit_cpi_event<T: Event>(event_authority: &AccountInfo, event_authority_bump: u8, event: &T) -> Result<()> {
+    let data: Vec<u8> = EVENT_IX_TAG_LE.into_iter().chain(event.data()).collect();
+    let ix = Instruction::new_with_bytes(
+        crate::ID,
+        &data,
+        vec![AccountMeta::new_readonly(event_authority.key(), true)],
+    );
+
+    invoke_signed(
+        &ix,
+        std::slice::from_ref(event_authority),
+        &[&[EVENT_AUTHORITY_SEED, &[event_authority_bump]]],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+/// Leaf of an allowlist merkle tree: keccak256(wallet)
+pub fn allowlist_leaf(wallet: &Pubkey) -> [u8; 32] {
+    keccak::hash(wallet.as_ref()).to_bytes()
+}
+
+/// Verifies `leaf` is in the tree with `root`. Pairs are hashed in sorted order,
+/// so the proof does not need to say which side each sibling is on.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
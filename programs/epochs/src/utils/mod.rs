@@ -1,7 +1,13 @@
 pub mod traits;
 pub mod validate;
 pub mod nifty;
+pub mod settlement;
+pub mod events;
+pub mod merkle;
 
 pub use traits::*;
 pub use validate::*;
 pub use nifty::*;
+pub use settlement::*;
+pub use events::*;
+pub use merkle::*;
@@ -4,8 +4,8 @@ use anchor_lang::{
 };
 use nifty_asset::{
     allocate_and_write, 
-    extensions::{AttributesBuilder, ExtensionBuilder}, 
-    instructions::{AllocateBuilder, CreateBuilder}, 
+    extensions::{AttributesBuilder, ExtensionBuilder, GroupingBuilder, MetadataBuilder}, 
+    instructions::{AllocateBuilder, BurnBuilder, CreateBuilder, TransferBuilder}, 
     types::{ExtensionInput, ExtensionType, Standard}
 };
 
@@ -104,6 +104,157 @@ pub fn create_asset(
     Ok(())
 }
 
+/// Accounts of a badge created by `create_badge`
+pub struct BadgeAccounts {
+    pub asset: Pubkey,
+    pub payer: Pubkey,
+    pub authority: Pubkey,
+    pub owner: Pubkey,
+    pub group: Pubkey,
+}
+
+/// Creates a soulbound reputation badge owned by `owner` in the badge group, tagged with its tier
+pub fn create_badge(
+    badge: &BadgeAccounts,
+    tier_name: &str,
+    account_infos: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]; 2],
+) -> Result<()> {
+    let mut attributes_builder = AttributesBuilder::default();
+    attributes_builder.add("Tier", tier_name);
+    allocate_extension(
+        badge.asset,
+        badge.payer,
+        ExtensionType::Attributes,
+        attributes_builder.data(),
+        account_infos,
+        signer_seeds,
+    )?;
+
+    let create_ix = CreateBuilder::new()
+        .asset(badge.asset)
+        .authority(badge.authority, true)
+        .owner(badge.owner)
+        .group(Some(badge.group))
+        .payer(Some(badge.payer))
+        .system_program(Some(system_program::ID))
+        .name(format!("Epochs Reputation: {}", tier_name))
+        .standard(Standard::Soulbound)
+        .mutable(false)
+        .instruction();
+    invoke_signed(&create_ix, account_infos, signer_seeds)?;
+    Ok(())
+}
+
+/// Allocates a single extension on `asset`, paid by `payer`
+pub fn allocate_extension(
+    asset: Pubkey,
+    payer: Pubkey,
+    extension_type: ExtensionType,
+    data: Vec<u8>,
+    account_infos: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let allocate_ix: Instruction = AllocateBuilder::new()
+        .asset(asset)
+        .payer(Some(payer))
+        .system_program(Some(system_program::ID))
+        .extension(ExtensionInput {
+            extension_type,
+            length: data.len() as u32,
+            data: Some(data),
+        })
+        .instruction();
+    invoke_signed(&allocate_ix, account_infos, signer_seeds)?;
+    Ok(())
+}
+
+/// Writes the `Metadata` extension of one of the program's group assets
+pub fn write_group_metadata(
+    asset: Pubkey,
+    payer: Pubkey,
+    symbol: &str,
+    description: &str,
+    account_infos: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut metadata_builder = MetadataBuilder::default();
+    metadata_builder.set(Some(symbol), Some(description), Some("https://epochs.wtf/"));
+    allocate_extension(asset, payer, ExtensionType::Metadata, metadata_builder.data(), account_infos, signer_seeds)
+}
+
+/// Adds the `Grouping` extension, which lets other assets join `asset` as their group
+pub fn write_grouping(
+    asset: Pubkey,
+    payer: Pubkey,
+    account_infos: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut group_builder = GroupingBuilder::default();
+    allocate_extension(asset, payer, ExtensionType::Grouping, group_builder.data(), account_infos, signer_seeds)
+}
+
+/// Creates a group asset owned by the program authority. It stays mutable so royalties, metadata, etc. can change
+pub fn create_group_asset(
+    asset: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    name: &str,
+    account_infos: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let create_ix = CreateBuilder::new()
+        .asset(asset)
+        .authority(authority, false)
+        .owner(authority)
+        .group(None)
+        .payer(Some(payer))
+        .system_program(Some(system_program::ID))
+        .name(name.to_string())
+        .standard(Standard::NonFungible)
+        .mutable(true)
+        .instruction();
+    invoke_signed(&create_ix, account_infos, signer_seeds)?;
+    Ok(())
+}
+
+pub fn transfer_asset(
+    asset: Pubkey,
+    authority: Pubkey,
+    recipient: Pubkey,
+    group: Pubkey,
+    account_infos: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]; 1],
+) -> Result<()> {
+    let transfer_ix: Instruction = TransferBuilder::new()
+        .asset(asset)
+        .signer(authority)
+        .recipient(recipient)
+        .group(Some(group))
+        .instruction();
+    invoke_signed(&transfer_ix, account_infos, signer_seeds)?;
+    Ok(())
+}
+
+/// Burns an asset owned by `authority`, returning the asset's rent to `recipient`
+pub fn burn_asset(
+    asset: Pubkey,
+    authority: Pubkey,
+    recipient: Pubkey,
+    group: Pubkey,
+    account_infos: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]; 1],
+) -> Result<()> {
+    let burn_ix: Instruction = BurnBuilder::new()
+        .asset(asset)
+        .signer(authority)
+        .recipient(Some(recipient))
+        .group(Some(group))
+        .instruction();
+    invoke_signed(&burn_ix, account_infos, signer_seeds)?;
+    Ok(())
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize)]
 struct AllocateInstructionData {
     discriminator: u8,
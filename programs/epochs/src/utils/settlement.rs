@@ -0,0 +1,328 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+use anchor_spl::token::{self, TokenAccount};
+
+use crate::utils::{emit_cpi_event, transfer_asset};
+use crate::{
+    Auction, AuctionSettled, BidCredit, Config, EpochError, EscrowLedger, ReputationChanged, TokenCredit, AUCTION_ESCROW_SEED,
+    AUCTION_SEED, AUTHORITY_SEED,
+};
+
+/// Amounts paid out of escrow for a sale
+pub struct Distribution {
+    pub referral_amount: u64,
+    pub treasury_amount: u64,
+    /// In `Config::creators` order
+    pub creator_amounts: Vec<u64>,
+}
+
+impl Distribution {
+    /// Splits `amount` between the treasury and creators; any referral fee has already been taken out
+    fn new(config: &Config, amount: u64) -> Result<Self> {
+        let (treasury_amount, creator_amounts) = config.split(amount)?;
+        Ok(Self { referral_amount: 0, treasury_amount, creator_amounts })
+    }
+}
+
+/// Accounts that pay an auction's clearing price out of escrow.
+/// Shared by `auction_claim` and `settle_auction`, which lend it their own accounts.
+/// The token accounts are only needed for token auctions (`auction.params.bid_mint` is set).
+/// The referrer's credit accounts are only needed when the winning bid was referred.
+pub struct SettlementFunds<'a, 'info> {
+    pub config: &'a Config,
+    pub auction: &'a Account<'info, Auction>,
+    pub escrow_ledger: &'a mut Account<'info, EscrowLedger>,
+    pub auction_escrow: &'a AccountInfo<'info>,
+    pub dao_treasury: &'a AccountInfo<'info>,
+    pub referrer_credit: Option<&'a mut Account<'info, BidCredit>>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub auction_token_escrow: Option<&'a Account<'info, TokenAccount>>,
+    pub dao_treasury_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub referrer_token_credit: Option<&'a mut Account<'info, TokenCredit>>,
+    pub token_credit_vault: Option<&'a Account<'info, TokenAccount>>,
+    pub token_program: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'info> SettlementFunds<'_, 'info> {
+    /// Pays the clearing price out of escrow. The referrer of the winning bid (if any) is credited
+    /// `Config::referral_bps` of it first, to withdraw later; the rest goes to the treasury and creators.
+    /// The unused part of the winner's proxy maximum stays in their deposit until it is released.
+    /// `creator_wallets` are creator token accounts in token auctions.
+    pub fn distribute(self, escrow_bump: u8, creator_wallets: &[AccountInfo<'info>]) -> Result<Distribution> {
+        let price = self.auction.clearing_price();
+        let referral_amount = if self.auction.has_referrer() { self.config.referral_fee(price)? } else { 0 };
+        let proceeds = price.checked_sub(referral_amount).ok_or(EpochError::Underflow)?;
+
+        let distribution = if self.auction.params.bid_mint.is_none() {
+            // The referral fee stays in escrow, now owed to the referrer
+            self.escrow_ledger.withdraw(proceeds)?;
+            if referral_amount > 0 {
+                let Some(referrer_credit) = self.referrer_credit else {
+                    return err!(EpochError::InvalidReferrer);
+                };
+                referrer_credit.credit(referral_amount)?;
+            }
+            distribute_funds(
+                self.config,
+                proceeds,
+                self.auction_escrow,
+                self.dao_treasury,
+                creator_wallets,
+                self.system_program,
+                escrow_bump,
+            )?
+        } else {
+            let (Some(auction_token_escrow), Some(dao_treasury_token_account), Some(token_program)) = (
+                self.auction_token_escrow,
+                self.dao_treasury_token_account,
+                self.token_program,
+            ) else {
+                return err!(EpochError::MissingTokenAccounts);
+            };
+            if referral_amount > 0 {
+                let (Some(referrer_token_credit), Some(token_credit_vault)) = (self.referrer_token_credit, self.token_credit_vault) else {
+                    return err!(EpochError::InvalidReferrer);
+                };
+                referrer_token_credit.credit(referral_amount)?;
+                transfer_from_token_escrow(
+                    self.auction,
+                    &auction_token_escrow.to_account_info(),
+                    &token_credit_vault.to_account_info(),
+                    token_program,
+                    referral_amount,
+                )?;
+            }
+            distribute_tokens(
+                self.config,
+                proceeds,
+                self.auction,
+                auction_token_escrow,
+                dao_treasury_token_account,
+                creator_wallets,
+                token_program,
+            )?
+        };
+
+        Ok(Distribution { referral_amount, ..distribution })
+    }
+}
+
+/// Pays `amount` out of the auction escrow to the DAO treasury and creators according to the config split.
+/// `creator_wallets` must be passed in the same order as `config.creators`.
+pub fn distribute_funds<'info>(
+    config: &Config,
+    amount: u64,
+    auction_escrow: &AccountInfo<'info>,
+    dao_treasury: &AccountInfo<'info>,
+    creator_wallets: &[AccountInfo<'info>],
+    system_program: &AccountInfo<'info>,
+    escrow_bump: u8,
+) -> Result<Distribution> {
+    require!(dao_treasury.key() == config.dao_treasury, EpochError::InvalidTreasury);
+    require!(creator_wallets.len() == config.creators.len(), EpochError::InvalidCreator);
+
+    let distribution = Distribution::new(config, amount)?;
+    let dao_treasury_lamports = distribution.treasury_amount;
+
+    let bump = &[escrow_bump];
+    let seeds: &[&[u8]] = &[AUCTION_ESCROW_SEED.as_ref(), bump];
+    let signer_seeds = &[seeds];
+
+    if dao_treasury_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: auction_escrow.clone(),
+                    to: dao_treasury.clone(),
+                },
+            ).with_signer(signer_seeds),
+            dao_treasury_lamports,
+        )?;
+    }
+
+    for ((creator, creator_wallet), &lamports) in config.creators.iter().zip(creator_wallets.iter()).zip(distribution.creator_amounts.iter()) {
+        require!(creator.address == creator_wallet.key(), EpochError::InvalidCreator);
+        if lamports == 0 {
+            continue;
+        }
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: auction_escrow.clone(),
+                    to: creator_wallet.clone(),
+                },
+            ).with_signer(signer_seeds),
+            lamports,
+        )?;
+    }
+
+    Ok(distribution)
+}
+
+/// Transfers the auction's asset from the authority PDA to `recipient`.
+pub fn distribute_nft<'info>(
+    asset: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    group: &AccountInfo<'info>,
+    oss_program: &AccountInfo<'info>,
+    authority_bump: u8,
+) -> Result<()> {
+    let bump = &[authority_bump];
+    let seeds: &[&[u8]] = &[AUTHORITY_SEED.as_ref(), bump];
+    let signer_seeds = &[seeds];
+
+    let account_infos = vec![
+        asset.clone(),
+        authority.clone(),
+        recipient.clone(),
+        group.clone(),
+        oss_program.clone(),
+    ];
+
+    transfer_asset(
+        asset.key(),
+        authority.key(),
+        recipient.key(),
+        group.key(),
+        &account_infos,
+        signer_seeds,
+    )
+}
+
+/// Emits `AuctionSettled` for a sale, followed by the reputation awarded for it.
+pub fn emit_settlement_events(
+    event_authority: &AccountInfo,
+    event_authority_bump: u8,
+    auction: &Auction,
+    recipient: Pubkey,
+    distribution: Distribution,
+    reputation_changes: &[ReputationChanged],
+) -> Result<()> {
+    emit_cpi_event(
+        event_authority,
+        event_authority_bump,
+        &AuctionSettled {
+            epoch: auction.epoch,
+            winner: auction.high_bidder,
+            recipient,
+            price: auction.clearing_price(),
+            referrer: auction.referrer,
+            referral_amount: distribution.referral_amount,
+            treasury_amount: distribution.treasury_amount,
+            creator_amounts: distribution.creator_amounts,
+            bid_mint: auction.params.bid_mint,
+        },
+    )?;
+    for reputation_changed in reputation_changes {
+        emit_cpi_event(event_authority, event_authority_bump, reputation_changed)?;
+    }
+    Ok(())
+}
+
+/// Reimburses the authority PDA for the rent it refunded to the epoch initiator in `create_epoch`.
+pub fn pay_rent<'info>(
+    payer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    asset: &AccountInfo<'info>,
+    auction: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let asset_lamports = asset.lamports();
+    let auction_lamports = auction.lamports();
+    // Use saturating add to prevent any weird scenario where somebody is sending lamports to these accounts
+    let refund_amount: u64 = asset_lamports.saturating_add(auction_lamports).min(300000);
+
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: authority.clone(),
+            },
+        ),
+        refund_amount,
+    )?;
+
+    Ok(())
+}
+
+/// Transfers `amount` tokens out of an auction's token escrow, signed by the auction PDA that owns it.
+pub fn transfer_from_token_escrow<'info>(
+    auction: &Account<'info, Auction>,
+    auction_token_escrow: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let epoch = auction.epoch.to_le_bytes();
+    let bump = &[auction.bump];
+    let seeds: &[&[u8]] = &[AUCTION_SEED.as_bytes(), &epoch, bump];
+    let signer_seeds = &[seeds];
+
+    token::transfer(
+        CpiContext::new(
+            token_program.clone(),
+            token::Transfer {
+                from: auction_token_escrow.clone(),
+                to: to.clone(),
+                authority: auction.to_account_info(),
+            },
+        ).with_signer(signer_seeds),
+        amount,
+    )
+}
+
+/// Token equivalent of `distribute_funds`: pays `amount` out of the auction's token escrow
+/// to the DAO treasury's and creators' token accounts according to the config split.
+/// `creator_token_accounts` must be passed in the same order as `config.creators`.
+pub fn distribute_tokens<'info>(
+    config: &Config,
+    amount: u64,
+    auction: &Account<'info, Auction>,
+    auction_token_escrow: &Account<'info, TokenAccount>,
+    dao_treasury_token_account: &Account<'info, TokenAccount>,
+    creator_token_accounts: &[AccountInfo<'info>],
+    token_program: &AccountInfo<'info>,
+) -> Result<Distribution> {
+    let mint = auction_token_escrow.mint;
+    require!(
+        dao_treasury_token_account.owner == config.dao_treasury && dao_treasury_token_account.mint == mint,
+        EpochError::InvalidTreasury
+    );
+    require!(creator_token_accounts.len() == config.creators.len(), EpochError::InvalidCreator);
+
+    let distribution = Distribution::new(config, amount)?;
+    let dao_treasury_amount = distribution.treasury_amount;
+
+    transfer_from_token_escrow(
+        auction,
+        &auction_token_escrow.to_account_info(),
+        &dao_treasury_token_account.to_account_info(),
+        token_program,
+        dao_treasury_amount,
+    )?;
+
+    for ((creator, creator_token_account), &creator_amount) in config.creators.iter().zip(creator_token_accounts.iter()).zip(distribution.creator_amounts.iter()) {
+        require!(creator_token_account.owner == &token::ID, EpochError::InvalidCreator);
+        let token_account = TokenAccount::try_deserialize(&mut &creator_token_account.try_borrow_data()?[..])?;
+        require!(
+            token_account.owner == creator.address && token_account.mint == mint,
+            EpochError::InvalidCreator
+        );
+        transfer_from_token_escrow(
+            auction,
+            &auction_token_escrow.to_account_info(),
+            creator_token_account,
+            token_program,
+            creator_amount,
+        )?;
+    }
+
+    Ok(distribution)
+}
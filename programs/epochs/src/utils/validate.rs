@@ -1,5 +1,5 @@
 use crate::EpochError;
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::epoch_schedule::EpochSchedule};
 
 /// To be used with instructions only allowed within the current epoch
 /// (e.g., MintNft, AuctionBid)
@@ -18,9 +18,18 @@ pub fn get_and_validate_epoch(input_epoch: u64) -> Result<u64> {
     Ok(current_epoch)
 }
 
-pub fn verify_epoch_has_passed(input_epoch: u64) -> Result<()> {
-    let current_epoch = Clock::get()?.epoch;
-    require!(input_epoch < current_epoch, EpochError::FutureEpochNotAllowed);
+/// Returns the first slot of the epoch after `epoch` (i.e., when an auction for `epoch` would naturally end)
+pub fn get_epoch_end_slot(epoch: u64) -> Result<u64> {
+    let next_epoch = epoch.checked_add(1).ok_or(EpochError::Overflow)?;
+    Ok(EpochSchedule::get()?.get_first_slot_in_epoch(next_epoch))
+}
 
-    Ok(())
+/// Returns the first slot after `grace_epochs` full epochs have passed since the epoch containing `ended_slot`
+pub fn get_grace_end_slot(ended_slot: u64, grace_epochs: u64) -> Result<u64> {
+    let epoch_schedule = EpochSchedule::get()?;
+    let grace_end_epoch = epoch_schedule
+        .get_epoch(ended_slot)
+        .checked_add(grace_epochs)
+        .ok_or(EpochError::Overflow)?;
+    Ok(epoch_schedule.get_first_slot_in_epoch(grace_end_epoch))
 }
\ No newline at end of file
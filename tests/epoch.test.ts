@@ -1,13 +1,15 @@
-import { Keypair, LAMPORTS_PER_SOL, sendAndConfirmTransaction, SystemProgram } from "@solana/web3.js";
-import { airdropToMultiple, initIdlToChain, waitTilEpochIs, waitUntilTimeStamp } from "./utils/utils";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, sendAndConfirmTransaction, SystemProgram } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { randomBytes } from "crypto";
+import { airdropToMultiple, initIdlToChain, sendTransaction, waitTilEpochIs, waitTilSlot, waitUntilTimeStamp } from "./utils/utils";
 import { assert, expect } from "chai";
 import { ReputationPoints, ReputationTracker } from "./utils/reputation";
 import { AUTHORITY } from "./utils/consts";
 import { EpochClient } from "@epochs/api";
-import { performMinterClaim, performMinterRedeem } from "./utils/instructions/timeMachine";
 import { Asset } from "@epochs/api/utils/deserialize/deserialize";
 import { performRandomBid } from "./utils/instructions/bid";
-import { CREATOR1_WALLET, CREATOR2_WALLET, DAO_TREASURY } from "@epochs/api/utils";
+import { CREATOR1_WALLET, DAO_TREASURY, getSealedBidCommitment } from "@epochs/api/utils";
+import { BPS_DENOMINATOR, POINTS_TABLE_ARGS, REFERRAL_BPS, getConfigArgs, getExpectedSplit } from "./utils/config";
 
 describe("The Epochs Program", () => {
     const epochClient = EpochClient.local();
@@ -38,6 +40,47 @@ describe("The Epochs Program", () => {
     });
 
 
+    describe("Config Initiation", () => {
+        it("should prevent unauthorized initialization of the Config", async () => {
+            try {
+                const tx = await epochClient.createInitializeConfigTransaction({ admin: bidder1.publicKey, args: getConfigArgs() });
+                await sendTransaction(epochClient.connection, tx, [bidder1]);
+                assert.fail('Expected config initialization to fail');
+            } catch (err) {
+                assert.ok(err, "Expected config initialization to fail");
+            }
+        });
+
+        it("should initialize the Config, Points Table and Leaderboard", async () => {
+            const configTx = await epochClient.createInitializeConfigTransaction({ admin: AUTHORITY.publicKey, args: getConfigArgs() });
+            await sendTransaction(epochClient.connection, configTx, [AUTHORITY]);
+            const pointsTableTx = await epochClient.createInitializePointsTableTransaction({ admin: AUTHORITY.publicKey, args: POINTS_TABLE_ARGS });
+            await sendTransaction(epochClient.connection, pointsTableTx, [AUTHORITY]);
+            const leaderboardTx = await epochClient.createInitializeLeaderboardTransaction({ admin: AUTHORITY.publicKey });
+            await sendTransaction(epochClient.connection, leaderboardTx, [AUTHORITY]);
+
+            const config = await epochClient.fetchConfig();
+            assert.strictEqual(config.admin.toBase58(), AUTHORITY.publicKey.toBase58(), "Expected admin to be the authority");
+            assert.strictEqual(config.daoTreasury.toBase58(), DAO_TREASURY.toBase58(), "Expected DAO treasury to match");
+            assert.strictEqual(config.creators.length, 1, "Expected a single creator");
+            assert.strictEqual(config.creators[0].address.toBase58(), CREATOR1_WALLET.toBase58(), "Expected creator to match");
+
+            const pointsTable = await epochClient.fetchPointsTable();
+            assert.strictEqual(pointsTable.initiate.toNumber(), ReputationPoints.INITIATE, "Expected initiate points to match");
+            assert.strictEqual(pointsTable.bid.toNumber(), ReputationPoints.BID, "Expected bid points to match");
+        });
+
+        it("should prevent reinitialization of the Config", async () => {
+            try {
+                const tx = await epochClient.createInitializeConfigTransaction({ admin: AUTHORITY.publicKey, args: getConfigArgs() });
+                await sendTransaction(epochClient.connection, tx, [AUTHORITY]);
+                assert.fail('Expected config initialization to fail');
+            } catch (err) {
+                assert.ok(err, "Expected config initialization to fail");
+            }
+        });
+    });
+
     describe("Group Initiation", () => {
         describe("Unauthorized signer cannot create Group Asset", async () => {
             it("should prevent unauthorized creation of a Group Asset", async () => {
@@ -173,7 +216,7 @@ describe("The Epochs Program", () => {
                     assert.strictEqual(auction.epoch.toNumber(), testEpoch, "Expected epoch to match test epoch");
                     assert.strictEqual(auction.mint.toBase58(), epochClient.fetchAssetAddress({ epoch: testEpoch }).toBase58(), "Expected address to match mint PDA");
                     assert.strictEqual(auction.highBidLamports.toNumber(), 0, "Expected high bid to be 0");
-                    assert.strictEqual(auction.highBidder.toBase58(), payer.publicKey.toBase58(), "Expected high bidder to be system program");
+                    assert.strictEqual(auction.highBidder.toBase58(), PublicKey.default.toBase58(), "Expected no high bidder");
                     assert.deepStrictEqual(auction.state, { unClaimed: {} }, "Expected auction state to be unclaimed");
                 } catch (err) {
                     console.error(err);
@@ -209,7 +252,7 @@ describe("The Epochs Program", () => {
             it("should accurately reflect escrow balances, reputation, and high bids throughout a simulated bidding war", async () => {
                 const numberOfBids = 10 + Math.floor(Math.random() * 5); // Random number of bids
                 let lastBidAmount = LAMPORTS_PER_SOL; // Starting bid amount for the auction
-                let lastBidder = payer; // Initiator is the first high bidder
+                let lastBidder: Keypair | undefined; // No high bidder until the first bid
 
                 for (let i = 0; i < numberOfBids; i++) {
                     const bidResult = await performRandomBid({
@@ -218,7 +261,7 @@ describe("The Epochs Program", () => {
                         bidders: [bidder1, bidder2, bidder3],
                         reputationTrackers,
                         lastBidAmount: lastBidAmount,
-                        lastBidder: lastBidder.publicKey,
+                        lastBidder: lastBidder?.publicKey ?? PublicKey.default,
                     });
                     lastBidAmount = bidResult.bidAmount;
                     lastBidder = bidResult.highBidder;
@@ -287,9 +330,8 @@ describe("The Epochs Program", () => {
                 const { highBidder: winner, highBidLamports } = await epochClient.fetchAuction({ epoch: testEpoch });
                 const winnerKey = auctionResults.get(testEpoch).highBidder;
 
-                const [preBalanceCreator1, preBalanceCreator2, preBalanceTreasury, preBalanceAuctionEscrow] = await Promise.all([
+                const [preBalanceCreator1, preBalanceTreasury, preBalanceAuctionEscrow] = await Promise.all([
                     epochClient.connection.getBalance(CREATOR1_WALLET),
-                    epochClient.connection.getBalance(CREATOR2_WALLET),
                     epochClient.connection.getBalance(DAO_TREASURY),
                     epochClient.connection.getBalance(epochClient.fetchAuctionEscrowPda())
                 ]);
@@ -303,32 +345,588 @@ describe("The Epochs Program", () => {
                     const sig = await sendAndConfirmTransaction(epochClient.connection, tx, [winnerKey]);
                     assert.ok(sig, 'should have signature');
 
-                    const [postBalanceCreator1, postBalanceCreator2, postBalanceTreasury, postBalanceAuctionEscrow] = await Promise.all([
+                    const [postBalanceCreator1, postBalanceTreasury, postBalanceAuctionEscrow] = await Promise.all([
                         epochClient.connection.getBalance(CREATOR1_WALLET),
-                        epochClient.connection.getBalance(CREATOR2_WALLET),
                         epochClient.connection.getBalance(DAO_TREASURY),
                         epochClient.connection.getBalance(epochClient.fetchAuctionEscrowPda())
                     ]);
 
-                    const DAO_TREASURY_PERCENTAGE = 90;
-                    const CREATOR1_PERCENTAGE = 10;
-
-                    const totalDistributed = highBidLamports.toNumber(); 
-                    const expectedDaoReciept = Math.floor(totalDistributed * DAO_TREASURY_PERCENTAGE / 100);
-                    const expectedDaoTreasuryBalance = preBalanceTreasury + expectedDaoReciept;
-                    const expectedCreator1Balance = preBalanceCreator1 + (totalDistributed - expectedDaoReciept);
-                    //const expectedCreator2Balance = preBalanceCreator2 + (totalDistributed - (expectedDaoTreasuryBalance + expectedCreator1Balance));
+                    // The split comes from the on-chain config: 90% to the DAO treasury, 10% to creator 1
+                    const totalDistributed = highBidLamports.toNumber();
+                    const { creator1Amount, treasuryAmount } = getExpectedSplit(totalDistributed);
+                    const expectedDaoTreasuryBalance = preBalanceTreasury + treasuryAmount;
+                    const expectedCreator1Balance = preBalanceCreator1 + creator1Amount;
+                    // Outbid deposits stay in escrow until they are released and withdrawn
                     const expectedAuctionEscrowBalance = preBalanceAuctionEscrow - totalDistributed;
                     expect(postBalanceTreasury).to.equal(expectedDaoTreasuryBalance, "DAO treasury balance did not match the expected value after distribution.");
                     expect(postBalanceCreator1).to.equal(expectedCreator1Balance, "Creator1 balance did not match the expected value after distribution.");
-                    //expect(postBalanceCreator2).to.equal(expectedCreator2Balance, "Creator2 balance did not match the expected value after distribution.");
                     expect(postBalanceAuctionEscrow).to.equal(expectedAuctionEscrowBalance, "Auction escrow balance did not match the expected value after distribution.");
-                    expect(postBalanceAuctionEscrow).to.equal(0, "Auction escrow balance should be 0 after distribution.");
+
+                    const auction = await epochClient.fetchAuction({ epoch: testEpoch });
+                    assert.deepStrictEqual(auction.state, { claimed: {} }, "Expected auction state to be claimed");
                 } catch (err) {
                     console.log(err);
                     assert.fail('error minting', err);
                 }
             });
         });
+        describe("Bid Credits", () => {
+            it("should credit each released deposit to its bidder", async () => {
+                const { highBidder: winner, highBidLamports } = await epochClient.fetchAuction({ epoch: testEpoch });
+
+                for (const bidder of [bidder1, bidder2, bidder3]) {
+                    let deposit;
+                    try {
+                        deposit = await epochClient.fetchBidDeposit({ epoch: testEpoch, bidder: bidder.publicKey });
+                    } catch (error) {
+                        continue; // Did not bid in this auction
+                    }
+                    const { lamports: preCredit } = await epochClient.fetchBidCredit({ bidder: bidder.publicKey });
+
+                    // Permissionless, so the initiator cleans up every deposit
+                    const tx = await epochClient.createReleaseBidDepositTransaction({ epoch: testEpoch, bidder: bidder.publicKey });
+                    await sendTransaction(epochClient.connection, tx, [payer]);
+
+                    const { lamports: postCredit } = await epochClient.fetchBidCredit({ bidder: bidder.publicKey });
+                    // The winner only gets back what the price did not use
+                    const expectedRelease = bidder.publicKey.equals(winner)
+                        ? deposit.amount.toNumber() - highBidLamports.toNumber()
+                        : deposit.amount.toNumber();
+                    assert.strictEqual(postCredit.toNumber(), preCredit.toNumber() + expectedRelease, "Expected the released deposit to be credited");
+                }
+
+                const auction = await epochClient.fetchAuction({ epoch: testEpoch });
+                assert.strictEqual(auction.openDeposits.toNumber(), 0, "Expected every deposit to be released");
+            });
+
+            it("should pay out the full credit on withdrawal", async () => {
+                const auctionEscrow = epochClient.fetchAuctionEscrowPda();
+
+                for (const bidder of [bidder1, bidder2, bidder3]) {
+                    let credit: number;
+                    try {
+                        credit = (await epochClient.fetchBidCredit({ bidder: bidder.publicKey })).lamports.toNumber();
+                    } catch (error) {
+                        continue; // Never bid
+                    }
+                    if (credit === 0) continue;
+                    const [preBalanceBidder, preBalanceEscrow] = await Promise.all([
+                        epochClient.connection.getBalance(bidder.publicKey),
+                        epochClient.connection.getBalance(auctionEscrow)
+                    ]);
+
+                    // The initiator pays the fee so the bidder's balance only moves by the credit
+                    const tx = await epochClient.createWithdrawCreditTransaction({ bidder: bidder.publicKey });
+                    await sendTransaction(epochClient.connection, tx, [payer, bidder]);
+
+                    const [postBalanceBidder, postBalanceEscrow, postCredit] = await Promise.all([
+                        epochClient.connection.getBalance(bidder.publicKey),
+                        epochClient.connection.getBalance(auctionEscrow),
+                        epochClient.fetchBidCredit({ bidder: bidder.publicKey })
+                    ]);
+                    expect(postBalanceBidder).to.equal(preBalanceBidder + credit, "Bidder should receive their full credit");
+                    expect(postBalanceEscrow).to.equal(preBalanceEscrow - credit, "Credit should be paid out of the auction escrow");
+                    expect(postCredit.lamports.toNumber()).to.equal(0, "Credit should be empty after withdrawal");
+                }
+            });
+
+            it("should prevent withdrawing an empty credit", async () => {
+                try {
+                    const tx = await epochClient.createWithdrawCreditTransaction({ bidder: bidder1.publicKey });
+                    await sendTransaction(epochClient.connection, tx, [bidder1]);
+                    assert.fail('Expected withdrawal to fail');
+                } catch (err) {
+                    assert.ok(err, "Expected withdrawal to fail");
+                }
+            });
+        });
+        describe("Auction Closing", () => {
+            it("should close the settled auction and return its rent to the authority", async () => {
+                const auctionPda = epochClient.fetchAuctionAddress({ epoch: testEpoch });
+                const bidHistoryPda = epochClient.fetchBidHistoryAddress({ epoch: testEpoch });
+                const [preBalanceAuthority, auctionRent, bidHistoryRent] = await Promise.all([
+                    epochClient.connection.getBalance(authorityPda),
+                    epochClient.connection.getBalance(auctionPda),
+                    epochClient.connection.getBalance(bidHistoryPda)
+                ]);
+
+                const tx = await epochClient.createCloseAuctionTransaction({ epoch: testEpoch, closer: payer.publicKey });
+                await sendTransaction(epochClient.connection, tx, [payer]);
+
+                const [postBalanceAuthority, auctionInfo, bidHistoryInfo] = await Promise.all([
+                    epochClient.connection.getBalance(authorityPda),
+                    epochClient.connection.getAccountInfo(auctionPda),
+                    epochClient.connection.getAccountInfo(bidHistoryPda)
+                ]);
+                assert.isNull(auctionInfo, "Expected the auction account to be closed");
+                assert.isNull(bidHistoryInfo, "Expected the bid history account to be closed");
+                expect(postBalanceAuthority).to.equal(preBalanceAuthority + auctionRent + bidHistoryRent, "Authority should receive the closed accounts' rent");
+            });
+        });
+    });
+    describe("Sealed-Bid Auction", () => {
+        const REVEAL_WINDOW_SLOTS = 16;
+        const FORFEIT_BPS = 5_000;
+        // bidder3 never reveals, so half their deposit is forfeited to the DAO treasury
+        const sealedBids = [
+            { bidder: bidder1, bidLamports: 2 * LAMPORTS_PER_SOL, depositLamports: 3 * LAMPORTS_PER_SOL, salt: Array.from(randomBytes(32)), reveal: true },
+            { bidder: bidder2, bidLamports: 4 * LAMPORTS_PER_SOL, depositLamports: 5 * LAMPORTS_PER_SOL, salt: Array.from(randomBytes(32)), reveal: true },
+            { bidder: bidder3, bidLamports: 1 * LAMPORTS_PER_SOL, depositLamports: 1 * LAMPORTS_PER_SOL, salt: Array.from(randomBytes(32)), reveal: false },
+        ];
+        let sealedEpoch: number;
+        let endSlot: number;
+
+        before(async () => {
+            const kind = { sealed: { revealWindowSlots: new BN(REVEAL_WINDOW_SLOTS), forfeitBps: FORFEIT_BPS, pricing: { secondPrice: {} } } };
+            const configTx = await epochClient.createUpdateConfigTransaction({ admin: AUTHORITY.publicKey, args: getConfigArgs(kind) });
+            await sendTransaction(epochClient.connection, configTx, [AUTHORITY]);
+
+            const { epoch: currentEpoch } = await epochClient.connection.getEpochInfo();
+            sealedEpoch = await waitTilEpochIs(currentEpoch + 1, epochClient.connection);
+            const tx = await epochClient.createInitEpochTransaction({ payer: payer.publicKey });
+            await sendTransaction(epochClient.connection, tx, [payer]);
+            endSlot = (await epochClient.fetchAuction({ epoch: sealedEpoch })).endSlot.toNumber();
+        });
+
+        it("should reject open bids", async () => {
+            try {
+                const tx = await epochClient.createBidTransaction({ bidder: bidder1.publicKey, bidAmount: LAMPORTS_PER_SOL });
+                await sendTransaction(epochClient.connection, tx, [bidder1]);
+                assert.fail('Expected bid to fail');
+            } catch (err) {
+                assert.ok(err, "Expected bid to fail");
+            }
+        });
+
+        it("should escrow the deposit with each commitment", async () => {
+            const auctionEscrow = epochClient.fetchAuctionEscrowPda();
+
+            for (const { bidder, bidLamports, depositLamports, salt } of sealedBids) {
+                const preBalanceEscrow = await epochClient.connection.getBalance(auctionEscrow);
+                const commitment = getSealedBidCommitment(bidLamports, salt, bidder.publicKey);
+                const tx = await epochClient.createCommitBidTransaction({ bidder: bidder.publicKey, commitment, depositLamports });
+                await sendTransaction(epochClient.connection, tx, [bidder]);
+
+                const sealedBid = await epochClient.fetchSealedBid({ epoch: sealedEpoch, bidder: bidder.publicKey });
+                assert.deepStrictEqual(sealedBid.commitment, commitment, "Expected the commitment to be stored");
+                assert.strictEqual(sealedBid.depositLamports.toNumber(), depositLamports, "Expected the deposit to be recorded");
+                assert.strictEqual(await epochClient.connection.getBalance(auctionEscrow), preBalanceEscrow + depositLamports, "Expected the deposit in escrow");
+            }
+
+            const auction = await epochClient.fetchAuction({ epoch: sealedEpoch });
+            assert.strictEqual(auction.highBidder.toBase58(), PublicKey.default.toBase58(), "Expected commitments to hide the high bidder");
+            assert.strictEqual(auction.unresolvedSealedBids.toNumber(), sealedBids.length, "Expected every commitment to be unresolved");
+        });
+
+        it("should prevent revealing before the auction ends", async function () {
+            // skip test if the auction has already ended
+            if (await epochClient.connection.getSlot() >= endSlot) {
+                this.skip();
+            }
+            const { bidder, bidLamports, salt } = sealedBids[0];
+            try {
+                const tx = await epochClient.createRevealBidTransaction({ epoch: sealedEpoch, bidder: bidder.publicKey, bidLamports, salt });
+                await sendTransaction(epochClient.connection, tx, [bidder]);
+                assert.fail('Expected reveal to fail');
+            } catch (err) {
+                assert.ok(err, "Expected reveal to fail");
+            }
+        });
+
+        it("should rank revealed bids once the auction ends", async () => {
+            await waitTilSlot(endSlot, epochClient.connection);
+
+            for (const { bidder, bidLamports, salt } of sealedBids.filter(({ reveal }) => reveal)) {
+                const tx = await epochClient.createRevealBidTransaction({ epoch: sealedEpoch, bidder: bidder.publicKey, bidLamports, salt });
+                await sendTransaction(epochClient.connection, tx, [bidder]);
+            }
+
+            const auction = await epochClient.fetchAuction({ epoch: sealedEpoch });
+            assert.strictEqual(auction.highBidder.toBase58(), bidder2.publicKey.toBase58(), "Expected the highest revealed bid to lead");
+            assert.strictEqual(auction.highBidLamports.toNumber(), sealedBids[1].bidLamports, "Expected the high bid to match");
+            assert.strictEqual(auction.secondBidLamports.toNumber(), sealedBids[0].bidLamports, "Expected the second bid to match");
+        });
+
+        it("should reject a reveal that does not match the commitment", async () => {
+            const { bidder, bidLamports } = sealedBids[2];
+            try {
+                const tx = await epochClient.createRevealBidTransaction({ epoch: sealedEpoch, bidder: bidder.publicKey, bidLamports, salt: new Array(32).fill(0) });
+                await sendTransaction(epochClient.connection, tx, [bidder]);
+                assert.fail('Expected reveal to fail');
+            } catch (err) {
+                assert.ok(err, "Expected reveal to fail");
+            }
+        });
+
+        it("should credit every deposit back once the reveal window closes", async () => {
+            await waitTilSlot(endSlot + REVEAL_WINDOW_SLOTS, epochClient.connection);
+            // Second price: the winner pays the runner-up's bid
+            const clearingPrice = sealedBids[0].bidLamports;
+
+            for (const { bidder, depositLamports, reveal } of sealedBids) {
+                const [{ lamports: preCredit }, preBalanceTreasury] = await Promise.all([
+                    epochClient.fetchBidCredit({ bidder: bidder.publicKey }),
+                    epochClient.connection.getBalance(DAO_TREASURY)
+                ]);
+
+                // Permissionless, so the initiator resolves every commitment
+                const tx = await epochClient.createResolveSealedBidTransaction({ epoch: sealedEpoch, bidder: bidder.publicKey });
+                await sendTransaction(epochClient.connection, tx, [payer]);
+
+                const [{ lamports: postCredit }, postBalanceTreasury] = await Promise.all([
+                    epochClient.fetchBidCredit({ bidder: bidder.publicKey }),
+                    epochClient.connection.getBalance(DAO_TREASURY)
+                ]);
+                const forfeit = reveal ? 0 : Math.floor(depositLamports * FORFEIT_BPS / BPS_DENOMINATOR);
+                const owed = bidder.publicKey.equals(bidder2.publicKey) ? clearingPrice : 0;
+                assert.strictEqual(postCredit.toNumber(), preCredit.toNumber() + depositLamports - forfeit - owed, "Expected the deposit less any forfeit and price to be credited");
+                assert.strictEqual(postBalanceTreasury, preBalanceTreasury + forfeit, "Expected the forfeit to go to the DAO treasury");
+            }
+
+            const auction = await epochClient.fetchAuction({ epoch: sealedEpoch });
+            assert.strictEqual(auction.unresolvedSealedBids.toNumber(), 0, "Expected every commitment to be resolved");
+        });
+
+        it("should let the winner claim at the second price", async () => {
+            const [preBalanceCreator1, preBalanceTreasury] = await Promise.all([
+                epochClient.connection.getBalance(CREATOR1_WALLET),
+                epochClient.connection.getBalance(DAO_TREASURY)
+            ]);
+
+            const tx = await epochClient.createClaimInstruction({ winner: bidder2.publicKey, epoch: sealedEpoch });
+            await sendTransaction(epochClient.connection, tx, [bidder2]);
+
+            const [postBalanceCreator1, postBalanceTreasury] = await Promise.all([
+                epochClient.connection.getBalance(CREATOR1_WALLET),
+                epochClient.connection.getBalance(DAO_TREASURY)
+            ]);
+            const { creator1Amount, treasuryAmount } = getExpectedSplit(sealedBids[0].bidLamports);
+            expect(postBalanceTreasury).to.equal(preBalanceTreasury + treasuryAmount, "DAO treasury should receive its share of the second price");
+            expect(postBalanceCreator1).to.equal(preBalanceCreator1 + creator1Amount, "Creator1 should receive its share of the second price");
+
+            const auction = await epochClient.fetchAuction({ epoch: sealedEpoch });
+            assert.deepStrictEqual(auction.state, { claimed: {} }, "Expected auction state to be claimed");
+        });
+    });
+    describe("Dutch Auction", () => {
+        const START_PRICE = 5 * LAMPORTS_PER_SOL;
+        const FLOOR_PRICE = 1 * LAMPORTS_PER_SOL;
+        const DECAY_PER_SLOT = 0.01 * LAMPORTS_PER_SOL;
+        let dutchEpoch: number;
+
+        before(async () => {
+            const kind = { dutch: { startPrice: new BN(START_PRICE), floorPrice: new BN(FLOOR_PRICE), decayPerSlot: new BN(DECAY_PER_SLOT) } };
+            const configTx = await epochClient.createUpdateConfigTransaction({ admin: AUTHORITY.publicKey, args: getConfigArgs(kind) });
+            await sendTransaction(epochClient.connection, configTx, [AUTHORITY]);
+
+            const { epoch: currentEpoch } = await epochClient.connection.getEpochInfo();
+            dutchEpoch = await waitTilEpochIs(currentEpoch + 1, epochClient.connection);
+            const tx = await epochClient.createInitEpochTransaction({ payer: payer.publicKey });
+            await sendTransaction(epochClient.connection, tx, [payer]);
+        });
+
+        it("should prevent closing the auction before it is sold", async () => {
+            try {
+                const tx = await epochClient.createCloseAuctionTransaction({ epoch: dutchEpoch, closer: payer.publicKey });
+                await sendTransaction(epochClient.connection, tx, [payer]);
+                assert.fail('Expected close to fail');
+            } catch (err) {
+                assert.ok(err, "Expected close to fail");
+            }
+        });
+
+        it("should prevent buying below the current price", async () => {
+            try {
+                const tx = await epochClient.createBuyNowTransaction({ buyer: bidder1.publicKey, maxPrice: FLOOR_PRICE / 2 });
+                await sendTransaction(epochClient.connection, tx, [bidder1]);
+                assert.fail('Expected buy to fail');
+            } catch (err) {
+                assert.ok(err, "Expected buy to fail");
+            }
+        });
+
+        it("should sell immediately at the decayed price", async () => {
+            const [preBalanceCreator1, preBalanceTreasury, preReputation] = await Promise.all([
+                epochClient.connection.getBalance(CREATOR1_WALLET),
+                epochClient.connection.getBalance(DAO_TREASURY),
+                epochClient.fetchReputation({ user: bidder1.publicKey })
+            ]);
+
+            const tx = await epochClient.createBuyNowTransaction({ buyer: bidder1.publicKey, maxPrice: START_PRICE });
+            await sendTransaction(epochClient.connection, tx, [bidder1]);
+
+            const auction = await epochClient.fetchAuction({ epoch: dutchEpoch });
+            const price = auction.highBidLamports.toNumber();
+            assert.deepStrictEqual(auction.state, { claimed: {} }, "Expected the auction to be settled by the purchase");
+            assert.strictEqual(auction.highBidder.toBase58(), bidder1.publicKey.toBase58(), "Expected the buyer to win");
+            assert.isAtMost(price, START_PRICE, "Expected the price to start at the start price");
+            assert.isAtLeast(price, FLOOR_PRICE, "Expected the price to never decay below the floor");
+            assert.strictEqual((START_PRICE - price) % DECAY_PER_SLOT, 0, "Expected the price to decay per slot");
+
+            const [postBalanceCreator1, postBalanceTreasury, postReputation] = await Promise.all([
+                epochClient.connection.getBalance(CREATOR1_WALLET),
+                epochClient.connection.getBalance(DAO_TREASURY),
+                epochClient.fetchReputation({ user: bidder1.publicKey })
+            ]);
+            const { creator1Amount, treasuryAmount } = getExpectedSplit(price);
+            expect(postBalanceTreasury).to.equal(preBalanceTreasury + treasuryAmount, "DAO treasury should receive its share of the price");
+            expect(postBalanceCreator1).to.equal(preBalanceCreator1 + creator1Amount, "Creator1 should receive its share of the price");
+            expect(postReputation.reputation.toNumber()).to.equal(preReputation.reputation.toNumber() + ReputationPoints.WIN, "Buyer should earn the win points");
+        });
+
+        it("should prevent buying a sold auction", async () => {
+            try {
+                const tx = await epochClient.createBuyNowTransaction({ buyer: bidder2.publicKey, maxPrice: START_PRICE });
+                await sendTransaction(epochClient.connection, tx, [bidder2]);
+                assert.fail('Expected buy to fail');
+            } catch (err) {
+                assert.ok(err, "Expected buy to fail");
+            }
+        });
+    });
+    describe("Referral Payout", () => {
+        const referrer = Keypair.generate();
+        let referralEpoch: number;
+
+        before(async () => {
+            const configTx = await epochClient.createUpdateConfigTransaction({ admin: AUTHORITY.publicKey, args: getConfigArgs() });
+            await sendTransaction(epochClient.connection, configTx, [AUTHORITY]);
+
+            const { epoch: currentEpoch } = await epochClient.connection.getEpochInfo();
+            referralEpoch = await waitTilEpochIs(currentEpoch + 1, epochClient.connection);
+            const tx = await epochClient.createInitEpochTransaction({ payer: payer.publicKey });
+            await sendTransaction(epochClient.connection, tx, [payer]);
+        });
+
+        it("should prevent referring your own bid", async () => {
+            try {
+                const tx = await epochClient.createBidTransaction({ bidder: bidder1.publicKey, bidAmount: LAMPORTS_PER_SOL, referrer: bidder1.publicKey });
+                await sendTransaction(epochClient.connection, tx, [bidder1]);
+                assert.fail('Expected bid to fail');
+            } catch (err) {
+                assert.ok(err, "Expected bid to fail");
+            }
+        });
+
+        it("should record the referrer of the leading bid", async () => {
+            let tx = await epochClient.createBidTransaction({ bidder: bidder1.publicKey, bidAmount: LAMPORTS_PER_SOL });
+            await sendTransaction(epochClient.connection, tx, [bidder1]);
+            let auction = await epochClient.fetchAuction({ epoch: referralEpoch });
+            assert.strictEqual(auction.referrer.toBase58(), PublicKey.default.toBase58(), "Expected no referrer for an unreferred bid");
+
+            tx = await epochClient.createBidTransaction({ bidder: bidder2.publicKey, bidAmount: 2 * LAMPORTS_PER_SOL, referrer: referrer.publicKey });
+            await sendTransaction(epochClient.connection, tx, [bidder2]);
+            auction = await epochClient.fetchAuction({ epoch: referralEpoch });
+            assert.strictEqual(auction.referrer.toBase58(), referrer.publicKey.toBase58(), "Expected the referrer of the leading bid");
+
+            const { lamports } = await epochClient.fetchBidCredit({ bidder: referrer.publicKey });
+            assert.strictEqual(lamports.toNumber(), 0, "Expected the referrer's credit to be created empty");
+        });
+
+        it("should credit the referral fee at claim", async () => {
+            await waitTilEpochIs(referralEpoch + 1, epochClient.connection);
+            const auctionEscrow = epochClient.fetchAuctionEscrowPda();
+            const [preBalanceCreator1, preBalanceTreasury, preBalanceEscrow] = await Promise.all([
+                epochClient.connection.getBalance(CREATOR1_WALLET),
+                epochClient.connection.getBalance(DAO_TREASURY),
+                epochClient.connection.getBalance(auctionEscrow)
+            ]);
+
+            const tx = await epochClient.createClaimInstruction({ winner: bidder2.publicKey, epoch: referralEpoch });
+            await sendTransaction(epochClient.connection, tx, [bidder2]);
+
+            const [postBalanceCreator1, postBalanceTreasury, postBalanceEscrow, referrerCredit] = await Promise.all([
+                epochClient.connection.getBalance(CREATOR1_WALLET),
+                epochClient.connection.getBalance(DAO_TREASURY),
+                epochClient.connection.getBalance(auctionEscrow),
+                epochClient.fetchBidCredit({ bidder: referrer.publicKey })
+            ]);
+            const price = 2 * LAMPORTS_PER_SOL;
+            const referralFee = Math.floor(price * REFERRAL_BPS / BPS_DENOMINATOR);
+            const { creator1Amount, treasuryAmount } = getExpectedSplit(price - referralFee);
+            expect(referrerCredit.lamports.toNumber()).to.equal(referralFee, "Referrer should be credited the referral fee");
+            expect(postBalanceTreasury).to.equal(preBalanceTreasury + treasuryAmount, "DAO treasury should receive its share of the price less the fee");
+            expect(postBalanceCreator1).to.equal(preBalanceCreator1 + creator1Amount, "Creator1 should receive its share of the price less the fee");
+            // The fee stays in escrow until the referrer withdraws it
+            expect(postBalanceEscrow).to.equal(preBalanceEscrow - (price - referralFee), "Only the proceeds should leave escrow");
+        });
+
+        it("should let the referrer withdraw the fee", async () => {
+            const referralFee = Math.floor(2 * LAMPORTS_PER_SOL * REFERRAL_BPS / BPS_DENOMINATOR);
+            const tx = await epochClient.createWithdrawCreditTransaction({ bidder: referrer.publicKey });
+            await sendTransaction(epochClient.connection, tx, [payer, referrer]);
+
+            const balance = await epochClient.connection.getBalance(referrer.publicKey);
+            expect(balance).to.equal(referralFee, "Referrer should receive the referral fee");
+        });
+    });
+    describe("Win Transfer", () => {
+        const HIGH_BID = LAMPORTS_PER_SOL;
+        const TRANSFER_PRICE = 0.5 * LAMPORTS_PER_SOL;
+        let transferEpoch: number;
+
+        before(async () => {
+            const { epoch: currentEpoch } = await epochClient.connection.getEpochInfo();
+            transferEpoch = await waitTilEpochIs(currentEpoch + 1, epochClient.connection);
+            const initTx = await epochClient.createInitEpochTransaction({ payer: payer.publicKey });
+            await sendTransaction(epochClient.connection, initTx, [payer]);
+
+            const bidTx = await epochClient.createBidTransaction({ bidder: bidder1.publicKey, bidAmount: HIGH_BID });
+            await sendTransaction(epochClient.connection, bidTx, [bidder1]);
+            await waitTilEpochIs(transferEpoch + 1, epochClient.connection);
+        });
+
+        it("should prevent anyone but the winner from transferring the win", async () => {
+            try {
+                const tx = await epochClient.createTransferWinTransaction({ epoch: transferEpoch, winner: bidder2.publicKey, newWinner: bidder3.publicKey, priceLamports: 0 });
+                await sendTransaction(epochClient.connection, tx, [bidder2]);
+                assert.fail('Expected transfer to fail');
+            } catch (err) {
+                assert.ok(err, "Expected transfer to fail");
+            }
+        });
+
+        it("should move the win and its deposit to the new winner", async () => {
+            const { lamports: preCredit } = await epochClient.fetchBidCredit({ bidder: bidder1.publicKey });
+
+            const tx = await epochClient.createTransferWinTransaction({ epoch: transferEpoch, winner: bidder1.publicKey, newWinner: bidder3.publicKey, priceLamports: TRANSFER_PRICE });
+            await sendTransaction(epochClient.connection, tx, [bidder1, bidder3]);
+
+            const [auction, sellerDeposit, buyerDeposit, { lamports: postCredit }] = await Promise.all([
+                epochClient.fetchAuction({ epoch: transferEpoch }),
+                epochClient.fetchBidDeposit({ epoch: transferEpoch, bidder: bidder1.publicKey }),
+                epochClient.fetchBidDeposit({ epoch: transferEpoch, bidder: bidder3.publicKey }),
+                epochClient.fetchBidCredit({ bidder: bidder1.publicKey })
+            ]);
+            assert.strictEqual(auction.highBidder.toBase58(), bidder3.publicKey.toBase58(), "Expected the new winner to hold the win");
+            assert.strictEqual(auction.highBidLamports.toNumber(), HIGH_BID, "Expected the price owed to be unchanged");
+            assert.strictEqual(sellerDeposit.amount.toNumber(), 0, "Expected the high bid to leave the seller's deposit");
+            assert.strictEqual(buyerDeposit.amount.toNumber(), HIGH_BID, "Expected the high bid to move to the new winner's deposit");
+            assert.strictEqual(postCredit.toNumber(), preCredit.toNumber() + TRANSFER_PRICE, "Expected the seller to be credited the transfer price");
+        });
+
+        it("should let the new winner claim", async () => {
+            const [preBalanceCreator1, preBalanceTreasury] = await Promise.all([
+                epochClient.connection.getBalance(CREATOR1_WALLET),
+                epochClient.connection.getBalance(DAO_TREASURY)
+            ]);
+
+            const tx = await epochClient.createClaimInstruction({ winner: bidder3.publicKey, epoch: transferEpoch });
+            await sendTransaction(epochClient.connection, tx, [bidder3]);
+
+            const [postBalanceCreator1, postBalanceTreasury, auction] = await Promise.all([
+                epochClient.connection.getBalance(CREATOR1_WALLET),
+                epochClient.connection.getBalance(DAO_TREASURY),
+                epochClient.fetchAuction({ epoch: transferEpoch })
+            ]);
+            const { creator1Amount, treasuryAmount } = getExpectedSplit(HIGH_BID);
+            expect(postBalanceTreasury).to.equal(preBalanceTreasury + treasuryAmount, "DAO treasury should receive its share of the high bid");
+            expect(postBalanceCreator1).to.equal(preBalanceCreator1 + creator1Amount, "Creator1 should receive its share of the high bid");
+            assert.deepStrictEqual(auction.state, { claimed: {} }, "Expected auction state to be claimed");
+        });
+    });
+    describe("Reputation Badges", () => {
+        const BADGE_TIERS = [
+            { name: "Bronze", minReputation: new BN(ReputationPoints.INITIATE) },
+            { name: "Silver", minReputation: new BN(2 * ReputationPoints.INITIATE) },
+            { name: "Gold", minReputation: new BN(1_000_000) },
+        ];
+
+        it("should prevent unauthorized creation of the badge group", async () => {
+            try {
+                const tx = await epochClient.createBadgeGroupTransaction({ payer: bidder1.publicKey, tiers: BADGE_TIERS });
+                await sendTransaction(epochClient.connection, tx, [bidder1]);
+                assert.fail('Expected badge group creation to fail');
+            } catch (err) {
+                assert.ok(err, "Expected badge group creation to fail");
+            }
+        });
+
+        it("should create the badge group with its tiers", async () => {
+            const tx = await epochClient.createBadgeGroupTransaction({ payer: AUTHORITY.publicKey, tiers: BADGE_TIERS });
+            await sendTransaction(epochClient.connection, tx, [AUTHORITY]);
+
+            const badgeConfig = await epochClient.fetchBadgeConfig();
+            assert.deepStrictEqual(badgeConfig.tiers.map(({ name }) => name), BADGE_TIERS.map(({ name }) => name), "Expected the tiers to be stored in order");
+            assert.strictEqual(badgeConfig.mintedTiers, 0, "Expected no tiers to be minted yet");
+        });
+
+        it("should prevent minting a tier above the contributor's reputation", async () => {
+            try {
+                const tx = await epochClient.createMintBadgeTransaction({ contributor: payer.publicKey, tier: 2 });
+                await sendTransaction(epochClient.connection, tx, [payer]);
+                assert.fail('Expected badge mint to fail');
+            } catch (err) {
+                assert.ok(err, "Expected badge mint to fail");
+            }
+        });
+
+        it("should mint a badge for a qualifying tier", async () => {
+            // The initiator has earned INITIATE points for every auction so far
+            const tx = await epochClient.createMintBadgeTransaction({ contributor: payer.publicKey, tier: 0 });
+            await sendTransaction(epochClient.connection, tx, [payer]);
+
+            const [badge, badgeConfig] = await Promise.all([
+                epochClient.fetchBadge({ contributor: payer.publicKey }),
+                epochClient.fetchBadgeConfig()
+            ]);
+            assert.strictEqual(badge.tier, 0, "Expected the badge to hold the minted tier");
+            assert.strictEqual(badge.asset.toBase58(), epochClient.fetchBadgeAssetAddress({ contributor: payer.publicKey, tier: 0 }).toBase58(), "Expected the badge asset to match");
+            assert.strictEqual(badgeConfig.mintedTiers, 1, "Expected the minted tier to be recorded");
+        });
+
+        it("should upgrade the badge and burn the previous tier", async () => {
+            const tx = await epochClient.createMintBadgeTransaction({ contributor: payer.publicKey, tier: 1, currentTier: 0 });
+            await sendTransaction(epochClient.connection, tx, [payer]);
+
+            const [badge, previousAsset] = await Promise.all([
+                epochClient.fetchBadge({ contributor: payer.publicKey }),
+                epochClient.connection.getAccountInfo(epochClient.fetchBadgeAssetAddress({ contributor: payer.publicKey, tier: 0 }))
+            ]);
+            assert.strictEqual(badge.tier, 1, "Expected the badge to hold the upgraded tier");
+            assert.isNull(previousAsset, "Expected the previous badge asset to be burned");
+        });
+
+        it("should prevent dropping a tier that badges have been minted for", async () => {
+            try {
+                const tx = await epochClient.createUpdateBadgeTiersTransaction({ admin: AUTHORITY.publicKey, tiers: BADGE_TIERS.slice(0, 1) });
+                await sendTransaction(epochClient.connection, tx, [AUTHORITY]);
+                assert.fail('Expected badge tier update to fail');
+            } catch (err) {
+                assert.ok(err, "Expected badge tier update to fail");
+            }
+        });
+    });
+    describe("Reputation Leaderboard", () => {
+        it("should rank contributors by reputation", async () => {
+            const [leaderboard, initiatorReputation] = await Promise.all([
+                epochClient.fetchLeaderboard(),
+                epochClient.fetchReputation({ user: payer.publicKey })
+            ]);
+            const entries = leaderboard.entries.slice(0, leaderboard.len.toNumber());
+            assert.isAbove(entries.length, 0, "Expected the leaderboard to have entries");
+
+            entries.slice(1).forEach((entry, i) => {
+                assert.isAtMost(entry.reputation.toNumber(), entries[i].reputation.toNumber(), "Expected entries in descending order of reputation");
+            });
+            const initiatorEntry = entries.find(({ contributor }) => contributor.equals(payer.publicKey));
+            assert.ok(initiatorEntry, "Expected the initiator to be on the leaderboard");
+            assert.strictEqual(initiatorEntry.reputation.toNumber(), initiatorReputation.reputation.toNumber(), "Expected the initiator's entry to match their reputation");
+        });
+
+        it("should sync a contributor's reputation", async () => {
+            const tx = await epochClient.createSyncLeaderboardTransaction({ contributor: bidder1.publicKey });
+            await sendTransaction(epochClient.connection, tx, [payer]);
+
+            const [leaderboard, reputation] = await Promise.all([
+                epochClient.fetchLeaderboard(),
+                epochClient.fetchReputation({ user: bidder1.publicKey })
+            ]);
+            const entry = leaderboard.entries
+                .slice(0, leaderboard.len.toNumber())
+                .find(({ contributor }) => contributor.equals(bidder1.publicKey));
+            assert.ok(entry, "Expected the contributor to be on the leaderboard");
+            assert.strictEqual(entry.reputation.toNumber(), reputation.reputation.toNumber(), "Expected the entry to match the contributor's reputation");
+        });
     });
 });
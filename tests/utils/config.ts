@@ -0,0 +1,67 @@
+import { BN } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { CREATOR1_WALLET, DAO_TREASURY } from "@epochs/api/utils";
+import { AuctionKind, ConfigArgs, PointsTableArgs } from "@epochs/api/utils/types";
+import { AUTHORITY } from "./consts";
+import { ReputationPoints } from "./reputation";
+
+export const TREASURY_SHARE_BPS = 9_000;
+export const CREATOR1_SHARE_BPS = 1_000;
+export const REFERRAL_BPS = 500;
+export const BPS_DENOMINATOR = 10_000;
+
+/**
+ * Config used by the tests. Reputation does not decay and streaks earn no bonus,
+ * so every action earns exactly its `ReputationPoints`.
+ * @param {AuctionKind} kind Kind of the auctions created after the config is set.
+ */
+export function getConfigArgs(kind: AuctionKind = { english: {} }): ConfigArgs {
+    return {
+        admin: AUTHORITY.publicKey,
+        daoTreasury: DAO_TREASURY,
+        treasuryShareBps: TREASURY_SHARE_BPS,
+        creators: [{ address: CREATOR1_WALLET, shareBps: CREATOR1_SHARE_BPS }],
+        royaltyBps: 500,
+        auctionParams: {
+            reservePriceLamports: new BN(0),
+            minIncrementLamports: new BN(0.1 * LAMPORTS_PER_SOL),
+            minIncrementBps: 0,
+            extensionWindowSlots: new BN(0),
+            noBidPolicy: { treasury: {} },
+            kind,
+            bidMint: null,
+            claimGraceEpochs: new BN(0),
+            forfeitUnclaimedToDao: false,
+            gateWindowSlots: new BN(0),
+            gateMinReputation: new BN(0),
+            gateAllowlistRoot: new Array(32).fill(0),
+        },
+        referralBps: REFERRAL_BPS,
+        reputationParams: {
+            halfLifeEpochs: new BN(0),
+            streakBonusBps: 0,
+            maxStreakBonusBps: 0,
+        },
+    };
+}
+
+export const POINTS_TABLE_ARGS: PointsTableArgs = {
+    initiate: new BN(ReputationPoints.INITIATE),
+    bid: new BN(ReputationPoints.BID),
+    win: new BN(ReputationPoints.WIN),
+    settle: new BN(ReputationPoints.SETTLE),
+    forceSettle: new BN(ReputationPoints.SETTLE),
+    bidLamportsPerPoint: new BN(0),
+    maxBidSizePoints: new BN(0),
+    milestoneIntervalEpochs: new BN(0),
+    milestoneMultiplierBps: 0,
+};
+
+/**
+ * Creator and DAO treasury amounts for a sale, as split by the program:
+ * creators are rounded down and the treasury keeps the dust.
+ */
+export function getExpectedSplit(proceeds: number) {
+    const creator1Amount = Math.floor(proceeds * CREATOR1_SHARE_BPS / BPS_DENOMINATOR);
+    return { creator1Amount, treasuryAmount: proceeds - creator1Amount };
+}
@@ -9,7 +9,6 @@ export const SEEDS = {
     AUTHORITY: "Authority",
     COLLECTION: "Collection",
     EPOCH_INSCRIPTION: "EpochInscription",
-    MINTER: "Minter",
     NFT_MINT: "NftMint",
     REPUTATION: "Reputation"
 }
\ No newline at end of file
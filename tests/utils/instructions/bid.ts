@@ -1,5 +1,4 @@
 import { Keypair, PublicKey, LAMPORTS_PER_SOL, sendAndConfirmTransaction } from "@solana/web3.js";
-import * as anchor from "@coral-xyz/anchor";
 import { AnchorError } from "@coral-xyz/anchor";
 import { assert } from "chai";
 import { ReputationPoints, ReputationTracker } from "../reputation";
@@ -35,15 +34,18 @@ export async function bidOnAuction({
     try {
 
         const results = await Promise.allSettled([
-            client.connection.getBalance(highBidder),
-            client.fetchAuction({ epoch, commitment: 'processed' }),
+            client.fetchBidDeposit({ epoch, bidder: highBidder }),
+            client.fetchBidDeposit({ epoch, bidder: bidder.publicKey }),
+            client.fetchBidCredit({ bidder: bidder.publicKey }),
             client.connection.getBalance(auctionEscrow)
         ]);
 
         // Extracting and providing fallback values
-        const prevBidderInitialBalance = results[0].status === 'fulfilled' ? results[0].value : 0;
-        const { highBidLamports: prevBid } = results[1].status === 'fulfilled' ? results[1].value : { highBidLamports: new anchor.BN(0) };
-        const initialEscrowBalance = results[2].status === 'fulfilled' ? results[2].value : 0;
+        const prevBidderDeposit = results[0].status === 'fulfilled' ? results[0].value.amount.toNumber() : 0;
+        const bidderDeposit = results[1].status === 'fulfilled' ? results[1].value.amount.toNumber() : 0;
+        const bidderCredit = results[2].status === 'fulfilled' ? results[2].value.lamports.toNumber() : 0;
+        const initialEscrowBalance = results[3].status === 'fulfilled' ? results[3].value : 0;
+        const isRaise = highBidder.equals(bidder.publicKey);
 
         const tx = await client.createBidTransaction({ bidAmount, bidder: bidder.publicKey });
         const { blockhash, lastValidBlockHeight } = await client.connection.getLatestBlockhash();
@@ -54,7 +56,8 @@ export async function bidOnAuction({
         let sig = await sendAndConfirmTransaction(client.connection, tx, [bidder], { skipPreflight: true });
 
         if (logMintInfo) console.log(`   Epoch ${epoch} - bid signature: ${sig}`);
-        expectedReputation.addReputation(ReputationPoints.BID);
+        // Raising your own leading bid earns no points
+        if (!isRaise) expectedReputation.addReputation(ReputationPoints.BID);
 
         const finalAuctionData = await client.fetchAuction({ epoch, commitment: 'processed' });
         assert.strictEqual(finalAuctionData.epoch.toNumber(), epoch, "Auction epoch should match the input epoch");
@@ -69,15 +72,21 @@ export async function bidOnAuction({
             assert.strictEqual(reputationData.reputation.toNumber(), expectedReputation.getReputation(), "Reputation should match expected value");
         }
 
-        const [finalEscrowBalance, prevBidderFinalBalance] = await Promise.all([
+        const [finalEscrowBalance, finalBidderDeposit, finalBidderCredit] = await Promise.all([
             client.connection.getBalance(auctionEscrow),
-            client.connection.getBalance(highBidder)
+            client.fetchBidDeposit({ epoch, bidder: bidder.publicKey }),
+            client.fetchBidCredit({ bidder: bidder.publicKey })
         ]);
 
-        assert.strictEqual(finalEscrowBalance, initialEscrowBalance + bidAmount - prevBid.toNumber(), `After tx, final escrow should have the previous balance plus the new bid amount minus the previous bid amount - ${sig}`);
+        // Nothing is refunded directly: the whole bid is deposited and earlier deposits stay in escrow until released
+        assert.strictEqual(finalEscrowBalance, initialEscrowBalance + bidAmount, `After tx, final escrow should have the previous balance plus the new bid amount - ${sig}`);
+        assert.strictEqual(finalBidderDeposit.amount.toNumber(), bidAmount, `Bidder's deposit should hold the new bid - ${sig}`);
+        // The bidder's own earlier deposit in this auction is replaced and credited to them
+        assert.strictEqual(finalBidderCredit.lamports.toNumber(), bidderCredit + bidderDeposit, `Bidder's earlier deposit should have been credited - ${sig}`);
 
-        if (highBidder.toBase58() === bidder.publicKey.toBase58()) return; // need to skip this if previous bidder is the same as the current bidder
-        assert.strictEqual(prevBidderFinalBalance, prevBidderInitialBalance + prevBid.toNumber(), `Previous bidder should have had their bid refunded - ${sig}`);
+        if (isRaise || highBidder.equals(PublicKey.default)) return;
+        const prevBidderFinalDeposit = await client.fetchBidDeposit({ epoch, bidder: highBidder });
+        assert.strictEqual(prevBidderFinalDeposit.amount.toNumber(), prevBidderDeposit, `Previous bidder's deposit should stay in escrow until released - ${sig}`);
 
     } catch (error) {
         if (expectToFail) {
@@ -68,32 +68,11 @@ function getNftMintPda(program: Program<any>, epoch: number) {
     return nftMint;
 }
 
-function getMinterPda(program: Program<any>) {
-    const [minter] = PublicKey.findProgramAddressSync(
-        [Buffer.from(SEEDS.MINTER)],
-        program.programId
-    );
-    return minter;
-}
-
-function getMinterClaimPda(program: Program<any>, payer: PublicKey) {
-    const [minterClaim] = PublicKey.findProgramAddressSync(
-        [
-            Buffer.from("MinterClaim"),
-            payer.toBuffer()
-        ],
-        program.programId
-    );
-    return minterClaim;
-}
-
 export {
     getAuctionPda,
     getReputationPda,
     getAuthorityPda,
     getAuctionEscrowPda,
     getCollectionMintPda,
-    getNftMintPda,
-    getMinterPda,
-    getMinterClaimPda
+    getNftMintPda
 };
\ No newline at end of file
@@ -1,5 +1,5 @@
 import { EpochClient } from "@epochs/api";
-import { Connection, PublicKey } from "@solana/web3.js";
+import { Connection, Keypair, PublicKey, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
 import { exec } from "child_process";
 
 
@@ -106,6 +106,40 @@ async function waitTilEpochIs(
 }
 
 
+async function waitTilSlot(
+    targetSlot: number,
+    connection: Connection,
+    checkInterval: number = 400
+) {
+    let slot = await connection.getSlot();
+    while (targetSlot > slot) {
+        await new Promise(resolve => setTimeout(resolve, checkInterval));
+        slot = await connection.getSlot();
+    }
+    return slot;
+}
+
+
+/**
+ * Signs a transaction with the fee payer first and sends it.
+ * @param {Connection} connection Solana connection object.
+ * @param {Transaction} tx Transaction built by the EpochClient.
+ * @param {Keypair[]} signers Fee payer followed by any other required signers.
+ * @returns {Promise<string>} The transaction signature.
+ */
+async function sendTransaction(
+    connection: Connection,
+    tx: Transaction,
+    signers: Keypair[]
+): Promise<string> {
+    const { blockhash, lastValidBlockHeight } = await connection.getLatestBlockhash();
+    tx.recentBlockhash = blockhash;
+    tx.lastValidBlockHeight = lastValidBlockHeight;
+    tx.feePayer = signers[0].publicKey;
+    return sendAndConfirmTransaction(connection, tx, signers);
+}
+
+
 async function waitUntilTimeStamp(
     targetTimeStamp: number,
     checkInterval: number = 1000,
@@ -132,6 +166,8 @@ export {
     openFile,
     initIdlToChain,
     printTableData,
+    sendTransaction,
     waitTilEpochIs,
+    waitTilSlot,
     waitUntilTimeStamp
 };
\ No newline at end of file